@@ -0,0 +1,115 @@
+use crate::crc::crc16;
+use crate::Gain;
+
+#[cfg(feature = "defmt-03")]
+use defmt_03 as defmt;
+
+/// Errors decoding a stored [`Calibration`]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum CalibrationError {
+    /// The data does not start with the calibration magic bytes
+    Magic,
+    /// The encoding version is not supported
+    Version(u8),
+    /// The CRC16 of the data did not match
+    Crc,
+}
+
+/// Per-gain calibration applied to conversion results
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Calibration {
+    /// Offset in codes for each gain setting, subtracted from every conversion
+    pub offsets: [i32; 8],
+    /// Gain correction in parts per million for each gain setting, applied to voltages
+    pub gain_ppm: [i32; 8],
+}
+
+impl Calibration {
+    /// Magic bytes starting the encoding
+    const MAGIC: [u8; 2] = *b"AC";
+    /// Current encoding version
+    const VERSION: u8 = 1;
+    /// Length of the binary encoding
+    pub const ENCODED_LEN: usize = 2 + 1 + 8 * 8 + 2;
+
+    /// Encode for storage in EEPROM or flash.
+    ///
+    /// The layout is the magic bytes `AC`, a version byte, then for each gain setting from
+    /// [`Gain::X1`] to [`Gain::X128`] the offset and gain correction as little-endian `i32`s,
+    /// followed by the big-endian CRC16 of all preceding bytes.
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut out = [0u8; Self::ENCODED_LEN];
+        out[0..2].copy_from_slice(&Self::MAGIC);
+        out[2] = Self::VERSION;
+
+        for (i, chunk) in out[3..Self::ENCODED_LEN - 2].chunks_exact_mut(8).enumerate() {
+            chunk[0..4].copy_from_slice(&self.offsets[i].to_le_bytes());
+            chunk[4..8].copy_from_slice(&self.gain_ppm[i].to_le_bytes());
+        }
+
+        let crc = crc16(&out[..Self::ENCODED_LEN - 2]);
+        out[Self::ENCODED_LEN - 2..].copy_from_slice(&crc.to_be_bytes());
+        out
+    }
+
+    /// Decode from the encoding produced by [`Self::to_bytes`]
+    pub fn from_bytes(bytes: &[u8; Self::ENCODED_LEN]) -> Result<Self, CalibrationError> {
+        if bytes[0..2] != Self::MAGIC {
+            return Err(CalibrationError::Magic);
+        }
+        if bytes[2] != Self::VERSION {
+            return Err(CalibrationError::Version(bytes[2]));
+        }
+        let (data, crc) = bytes.split_at(Self::ENCODED_LEN - 2);
+        if crc16(data) != u16::from_be_bytes([crc[0], crc[1]]) {
+            return Err(CalibrationError::Crc);
+        }
+
+        let mut calibration = Self::default();
+        for (i, chunk) in data[3..].chunks_exact(8).enumerate() {
+            calibration.offsets[i] = i32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+            calibration.gain_ppm[i] = i32::from_le_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]);
+        }
+        Ok(calibration)
+    }
+
+    /// The offset for a gain setting
+    #[inline]
+    pub fn offset(&self, gain: Gain) -> i32 {
+        self.offsets[gain as usize]
+    }
+
+    /// Set the offset for a gain setting
+    #[inline]
+    pub fn set_offset(&mut self, gain: Gain, offset: i32) {
+        self.offsets[gain as usize] = offset;
+    }
+
+    /// The gain correction in parts per million for a gain setting
+    #[inline]
+    pub fn gain_correction_ppm(&self, gain: Gain) -> i32 {
+        self.gain_ppm[gain as usize]
+    }
+
+    /// Set the gain correction in parts per million for a gain setting
+    #[inline]
+    pub fn set_gain_correction_ppm(&mut self, gain: Gain, ppm: i32) {
+        self.gain_ppm[gain as usize] = ppm;
+    }
+
+    /// Apply the gain correction to a voltage in microvolts measured at a gain setting
+    #[inline]
+    pub fn correct_microvolts(&self, gain: Gain, microvolts: i32) -> i32 {
+        let scale = 1_000_000 + self.gain_correction_ppm(gain) as i64;
+        (microvolts as i64 * scale + 500_000).div_euclid(1_000_000) as i32
+    }
+
+    /// Apply the gain correction to a voltage in volts, or any other value proportional to the
+    /// conversion code, measured at a gain setting
+    #[inline]
+    pub fn correct(&self, gain: Gain, value: f32) -> f32 {
+        value * (1.0 + self.gain_correction_ppm(gain) as f32 * 1e-6)
+    }
+}
@@ -0,0 +1,204 @@
+use crate::{
+    ConversionMode, CurrentDac, CurrentMux, DataIntegrityMode, DataRate, Gain, Mux, Register, Register0, Register1,
+    Register2, Register3, Vref,
+};
+
+#[cfg(feature = "defmt-03")]
+use defmt_03 as defmt;
+
+/// The complete device configuration, covering all four registers.
+///
+/// `Default` is the power-on reset configuration.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Config {
+    pub mux: Mux,
+    pub gain: Gain,
+    pub pga_bypass: bool,
+    pub data_rate: DataRate,
+    pub conversion_mode: ConversionMode,
+    pub voltage_reference: Vref,
+    pub temperature_sensor_mode: bool,
+    pub data_count_enable: bool,
+    pub data_integrity_mode: DataIntegrityMode,
+    pub burn_out_source_enable: bool,
+    pub current_dac: CurrentDac,
+    pub current_mux_1: CurrentMux,
+    pub current_mux_2: CurrentMux,
+}
+
+impl Config {
+    /// Register 0 for this configuration
+    #[inline]
+    pub fn reg0(&self) -> Register0 {
+        Register0 {
+            mux: self.mux,
+            gain: self.gain,
+            pga_bypass: self.pga_bypass,
+        }
+    }
+
+    /// Register 1 for this configuration
+    #[inline]
+    pub fn reg1(&self) -> Register1 {
+        Register1 {
+            data_rate: self.data_rate,
+            conversion_mode: self.conversion_mode,
+            voltage_reference: self.voltage_reference,
+            temperature_sensor_mode: self.temperature_sensor_mode,
+        }
+    }
+
+    /// Register 2 for this configuration, with `data_ready` cleared
+    #[inline]
+    pub fn reg2(&self) -> Register2 {
+        Register2 {
+            data_ready: false,
+            data_count_enable: self.data_count_enable,
+            data_integrity_mode: self.data_integrity_mode,
+            burn_out_source_enable: self.burn_out_source_enable,
+            current_dac: self.current_dac,
+        }
+    }
+
+    /// Register 3 for this configuration
+    #[inline]
+    pub fn reg3(&self) -> Register3 {
+        Register3 {
+            current_mux_1: self.current_mux_1,
+            current_mux_2: self.current_mux_2,
+        }
+    }
+
+    /// All four registers, ready for [`crate::ADS122C04::write_regs`]
+    #[inline]
+    pub fn registers(&self) -> [Register; 4] {
+        [
+            Register::Reg0(self.reg0()),
+            Register::Reg1(self.reg1()),
+            Register::Reg2(self.reg2()),
+            Register::Reg3(self.reg3()),
+        ]
+    }
+
+    /// Check the configuration against the datasheet constraints
+    pub fn validate(&self) -> Result<(), Violations> {
+        let mut violations = Violations::default();
+        let idacs = [self.current_mux_1, self.current_mux_2];
+        let idacs_routed = idacs.iter().filter(|&&m| m != CurrentMux::Disabled).count();
+
+        if self.pga_bypass && self.gain.factor() > 4 {
+            violations.insert(Violation::PgaBypassWithHighGain);
+        }
+        if self.current_mux_1 != CurrentMux::Disabled && self.current_mux_1 == self.current_mux_2 {
+            violations.insert(Violation::IdacSameOutput);
+        }
+        match (self.current_dac, idacs_routed) {
+            (CurrentDac::Off, 0) => {}
+            (CurrentDac::Off, _) => violations.insert(Violation::IdacCurrentOff),
+            (_, 0) => violations.insert(Violation::IdacNotRouted),
+            _ => {}
+        }
+        if self.voltage_reference != Vref::External
+            && idacs.iter().any(|m| matches!(m, CurrentMux::Refp | CurrentMux::Refn))
+        {
+            violations.insert(Violation::IdacOnUnusedReference);
+        }
+        if matches!(self.mux, Mux::Vref | Mux::Supply) && !(self.pga_bypass && self.gain.factor() <= 4) {
+            violations.insert(Violation::MonitorWithPga);
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+
+    /// Update the fields covered by a register
+    pub fn set_register(&mut self, register: &Register) {
+        match *register {
+            Register::Reg0(r) => {
+                self.mux = r.mux;
+                self.gain = r.gain;
+                self.pga_bypass = r.pga_bypass;
+            }
+            Register::Reg1(r) => {
+                self.data_rate = r.data_rate;
+                self.conversion_mode = r.conversion_mode;
+                self.voltage_reference = r.voltage_reference;
+                self.temperature_sensor_mode = r.temperature_sensor_mode;
+            }
+            Register::Reg2(r) => {
+                self.data_count_enable = r.data_count_enable;
+                self.data_integrity_mode = r.data_integrity_mode;
+                self.burn_out_source_enable = r.burn_out_source_enable;
+                self.current_dac = r.current_dac;
+            }
+            Register::Reg3(r) => {
+                self.current_mux_1 = r.current_mux_1;
+                self.current_mux_2 = r.current_mux_2;
+            }
+        }
+    }
+}
+
+/// A datasheet constraint violated by a [`Config`]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+#[repr(u8)]
+pub enum Violation {
+    /// The PGA can only be bypassed for gains 1, 2 and 4
+    PgaBypassWithHighGain = 0,
+    /// IDAC1 and IDAC2 are routed to the same pin
+    IdacSameOutput = 1,
+    /// An IDAC current is set but neither IDAC is routed to a pin
+    IdacNotRouted = 2,
+    /// An IDAC is routed to a pin but the IDAC current is off
+    IdacCurrentOff = 3,
+    /// An IDAC is routed to REFP or REFN but the external reference is not selected
+    IdacOnUnusedReference = 4,
+    /// The reference and supply monitors require the PGA to be bypassed with gain 1, 2 or 4
+    MonitorWithPga = 5,
+}
+
+impl Violation {
+    const ALL: [Violation; 6] = [
+        Violation::PgaBypassWithHighGain,
+        Violation::IdacSameOutput,
+        Violation::IdacNotRouted,
+        Violation::IdacCurrentOff,
+        Violation::IdacOnUnusedReference,
+        Violation::MonitorWithPga,
+    ];
+}
+
+/// The set of constraints violated by a [`Config`]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Violations(u8);
+
+impl Violations {
+    /// No constraints are violated
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The given constraint is violated
+    #[inline]
+    pub fn contains(&self, violation: Violation) -> bool {
+        self.0 & (1 << violation as u8) != 0
+    }
+
+    /// Iterate over the violated constraints
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Violation> + '_ {
+        Violation::ALL.into_iter().filter(|&v| self.contains(v))
+    }
+
+    #[inline(always)]
+    fn insert(&mut self, violation: Violation) {
+        self.0 |= 1 << violation as u8;
+    }
+}
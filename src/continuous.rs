@@ -0,0 +1,49 @@
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{I2c, SevenBitAddress};
+
+use crate::{DrdyPin, Error, Sample, ADS122C04};
+
+/// A running continuous acquisition, created by [`ADS122C04::start_continuous`].
+///
+/// Conversions are stopped by [`Continuous::stop`]. If the handle is dropped instead, the power
+/// down command is issued before the next bus transaction of the device.
+pub struct Continuous<'a, I: I2c<SevenBitAddress>, D, T> {
+    device: &'a mut ADS122C04<I, D>,
+    delay: T,
+    stopped: bool,
+}
+
+impl<'a, I: I2c<SevenBitAddress>, D: DrdyPin, T: DelayNs> Continuous<'a, I, D, T> {
+    #[inline(always)]
+    pub(crate) fn new(device: &'a mut ADS122C04<I, D>, delay: T) -> Self {
+        Self {
+            device,
+            delay,
+            stopped: false,
+        }
+    }
+
+    /// Wait for and read the next sample
+    #[inline]
+    pub async fn next(&mut self) -> Result<Sample, Error<I::Error>> {
+        self.device.wait_for_conversion(&mut self.delay).await?;
+        self.device.read_sample().await
+    }
+
+    /// Stop conversions by powering down the device
+    #[inline]
+    pub async fn stop(mut self) -> Result<(), Error<I::Error>> {
+        let result = self.device.power_down().await;
+        self.stopped = result.is_ok();
+        result
+    }
+}
+
+impl<I: I2c<SevenBitAddress>, D, T> Drop for Continuous<'_, I, D, T> {
+    fn drop(&mut self) {
+        if !self.stopped {
+            self.device.power_down_pending = true;
+            self.device.converting = false;
+        }
+    }
+}
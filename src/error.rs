@@ -0,0 +1,52 @@
+use embedded_hal_async::i2c::{self, ErrorKind};
+
+use crate::{InvalidRegisterValue, Violations};
+
+#[cfg(feature = "defmt-03")]
+use defmt_03 as defmt;
+
+/// Errors returned by the driver
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum Error<E> {
+    /// Error from the underlying I2C bus
+    Bus(E),
+    /// The CRC16 of the conversion data did not match
+    Crc,
+    /// The inverted copy of the conversion data did not match
+    InvertedDataMismatch,
+    /// A register held a reserved value
+    InvalidRegisterValue { reg: u8, value: u8 },
+    /// Timed out waiting for a conversion
+    Timeout,
+    /// Error waiting on the DRDY pin
+    Pin,
+    /// The configuration violates datasheet constraints
+    InvalidConfig(Violations),
+    /// A measurement was outside the expected range
+    OutOfRange,
+    /// The active reference voltage has not been set
+    UnknownReference,
+    /// A register read back differently than written. `expected ^ actual` gives the differing bits.
+    VerifyFailed { reg: u8, expected: u8, actual: u8 },
+}
+
+impl<E: i2c::Error> i2c::Error for Error<E> {
+    #[inline]
+    fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Bus(e) => e.kind(),
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+impl<E> From<InvalidRegisterValue> for Error<E> {
+    #[inline]
+    fn from(value: InvalidRegisterValue) -> Self {
+        Error::InvalidRegisterValue {
+            reg: value.reg,
+            value: value.value,
+        }
+    }
+}
@@ -1,149 +1,740 @@
 #![doc = include_str!("../README.md")]
 #![no_std]
 
+mod calibration;
+mod config;
+mod continuous;
+mod crc;
+mod error;
 mod registers;
+mod sample;
 
+pub mod rtd;
+pub mod thermocouple;
+
+use core::future::Future;
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::digital::Wait;
 use embedded_hal_async::i2c::{I2c, SevenBitAddress};
+pub use calibration::*;
+pub use config::*;
+pub use continuous::*;
+pub use error::*;
 pub use registers::*;
+pub use sample::*;
+
+/// Internal reference voltage in microvolts
+pub const INTERNAL_REFERENCE_UV: u32 = 2_048_000;
+
+/// Conversion periods [`ADS122C04::poll_for_data`] waits before timing out
+const POLL_TIMEOUT_PERIODS: u32 = 4;
+
+/// Largest gain correction [`ADS122C04::calibrate_gain`] accepts, in parts per million
+const MAX_GAIN_CORRECTION_PPM: i64 = 100_000;
+
+/// Placeholder DRDY type for a device without the DRDY pin connected
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NoDrdy;
+
+/// A DRDY input the driver can wait on: [`NoDrdy`] or any pin implementing [`Wait`]
+pub trait DrdyPin {
+    /// Wait for DRDY to go low, returning `false` without waiting if no pin is connected
+    fn wait_for_drdy(&mut self) -> impl Future<Output = Result<bool, ()>>;
+}
+
+impl DrdyPin for NoDrdy {
+    #[inline(always)]
+    async fn wait_for_drdy(&mut self) -> Result<bool, ()> {
+        Ok(false)
+    }
+}
+
+impl<P: Wait> DrdyPin for P {
+    #[inline(always)]
+    async fn wait_for_drdy(&mut self) -> Result<bool, ()> {
+        self.wait_for_low().await.map(|_| true).map_err(|_| ())
+    }
+}
 
 /// The ADS122C04 device
-pub struct ADS122C04<I: I2c<SevenBitAddress>> {
+pub struct ADS122C04<I: I2c<SevenBitAddress>, D = NoDrdy> {
     i2c: I,
     address: SevenBitAddress,
+    drdy: D,
+    config: Config,
+    last_count: Option<u8>,
+    statistics: Statistics,
+    power_down_pending: bool,
+    /// Continuous conversions were started and have not been stopped
+    converting: bool,
+    external_reference_uv: Option<u32>,
+    supply_uv: Option<u32>,
+    calibration: Calibration,
 }
 
 impl<I: I2c<SevenBitAddress>> ADS122C04<I> {
     /// Create a new device from an I2C peripheral and address.
+    ///
+    /// The device is assumed to be in its power-on state. Call [`Self::reset`] or
+    /// [`Self::read_config`] if it may have been configured elsewhere.
     #[inline(always)]
     pub fn new(i2c: I, address: SevenBitAddress) -> Self {
+        Self::new_with_drdy(i2c, address, NoDrdy)
+    }
+}
+
+impl<I: I2c<SevenBitAddress>, P: Wait> ADS122C04<I, P> {
+    /// Wait for the DRDY pin to signal new conversion data.
+    ///
+    /// Returns immediately if DRDY is already low, otherwise awaits its falling edge.
+    #[inline]
+    pub async fn wait_for_data(&mut self) -> Result<(), Error<I::Error>> {
+        self.drdy.wait_for_low().await.map_err(|_| Error::Pin)
+    }
+}
+
+impl<I: I2c<SevenBitAddress>, D: DrdyPin> ADS122C04<I, D> {
+    /// Wait for new conversion data, on the DRDY pin if connected or by polling otherwise
+    #[inline]
+    pub async fn wait_for_conversion<T: DelayNs>(&mut self, delay: &mut T) -> Result<(), Error<I::Error>> {
+        if self.drdy.wait_for_drdy().await.map_err(|_| Error::Pin)? {
+            Ok(())
+        } else {
+            self.poll_for_data(delay).await
+        }
+    }
+
+    /// Perform a single-shot measurement.
+    ///
+    /// Writes the given registers, forcing single-shot conversion mode, triggers START/SYNC, waits
+    /// for the conversion and reads the result.
+    pub async fn measure_single<const N: usize, T: DelayNs>(
+        &mut self,
+        registers: &[Register; N],
+        delay: &mut T,
+    ) -> Result<Sample, Error<I::Error>> {
+        let mut registers = *registers;
+        for reg in registers.iter_mut() {
+            if let Register::Reg1(r) = reg {
+                r.conversion_mode = ConversionMode::Single;
+            }
+        }
+        if N > 0 {
+            self.write_regs(&registers).await?;
+        }
+
+        self.set_conversion_mode(ConversionMode::Single).await?;
+
+        self.start_sync().await?;
+        self.wait_for_conversion(delay).await?;
+        self.read_sample().await
+    }
+
+    /// Perform a single-shot measurement with a temporary configuration.
+    ///
+    /// Only the registers that differ are written. The previous configuration is restored
+    /// afterwards, restarting conversions if continuous conversions were running.
+    pub async fn measure_with_config<T: DelayNs>(
+        &mut self,
+        config: &Config,
+        delay: &mut T,
+    ) -> Result<Sample, Error<I::Error>> {
+        let previous = self.config;
+        let converting = self.converting;
+        self.update_config(config).await?;
+        let sample = self.measure_single(&[], delay).await;
+
+        self.restore_config(&previous, converting).await?;
+        sample
+    }
+
+    /// Restore a configuration saved before a temporary measurement, restarting conversions if
+    /// they were running
+    async fn restore_config(&mut self, previous: &Config, converting: bool) -> Result<(), Error<I::Error>> {
+        self.update_config(previous).await?;
+        if converting {
+            self.start_sync().await?;
+        }
+        Ok(())
+    }
+
+    /// Calibrate the offset for the active gain.
+    ///
+    /// Averages `samples` conversions with the inputs shorted, using the active gain and data
+    /// rate, and stores the result in the [`Calibration`] to be subtracted from later samples.
+    /// The previous configuration is restored afterwards.
+    pub async fn calibrate_offset<T: DelayNs>(&mut self, samples: u16, delay: &mut T) -> Result<i32, Error<I::Error>> {
+        let config = Config {
+            mux: Mux::Shorted,
+            temperature_sensor_mode: false,
+            ..self.config
+        };
+        // Samples already have the current offset subtracted
+        let offset = self
+            .average_with_config(&config, samples, delay)
+            .await?
+            .saturating_add(self.calibration.offset(config.gain));
+        self.calibration.set_offset(config.gain, offset);
+        Ok(offset)
+    }
+
+    /// Calibrate the gain correction for the active gain.
+    ///
+    /// Averages `samples` conversions of a known voltage of `expected_nv` nanovolts applied to
+    /// the active inputs, and stores the correction in the [`Calibration`] to be applied by
+    /// [`Self::to_microvolts`] and [`Self::to_volts`]. Calibrate the offset first. The previous
+    /// configuration is restored afterwards.
+    ///
+    /// Returns [`Error::OutOfRange`] if `expected_nv` is zero, or the measured voltage differs
+    /// from it by more than 10 %, which usually means the wrong input is connected.
+    pub async fn calibrate_gain<T: DelayNs>(
+        &mut self,
+        expected_nv: i64,
+        samples: u16,
+        delay: &mut T,
+    ) -> Result<i32, Error<I::Error>> {
+        let config = Config {
+            temperature_sensor_mode: false,
+            ..self.config
+        };
+        let reference_uv = self.reference_uv().ok_or(Error::UnknownReference)?;
+        let code = self.average_with_config(&config, samples, delay).await? as i64;
+
+        // Compare codes rather than microvolts, which are too coarse at high gain
+        let expected_code = expected_nv
+            .checked_mul((config.gain.factor() as i64) << 23)
+            .and_then(|n| n.checked_div(reference_uv as i64 * 1000))
+            .ok_or(Error::OutOfRange)?;
+        if expected_code == 0 || code == 0 || (code < 0) != (expected_code < 0) {
+            return Err(Error::OutOfRange);
+        }
+        let ppm = expected_code * 1_000_000 / code - 1_000_000;
+        if ppm.abs() > MAX_GAIN_CORRECTION_PPM {
+            return Err(Error::OutOfRange);
+        }
+        self.calibration.set_gain_correction_ppm(config.gain, ppm as i32);
+        Ok(ppm as i32)
+    }
+
+    /// Average single-shot conversions with a temporary configuration
+    async fn average_with_config<T: DelayNs>(
+        &mut self,
+        config: &Config,
+        samples: u16,
+        delay: &mut T,
+    ) -> Result<i32, Error<I::Error>> {
+        let previous = self.config;
+        let converting = self.converting;
+        self.update_config(config).await?;
+
+        let samples = samples.max(1);
+        let mut sum = 0i64;
+        let mut result = Ok(());
+        for _ in 0..samples {
+            match self.measure_single(&[], delay).await {
+                Ok(sample) => sum += sample.code as i64,
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        self.restore_config(&previous, converting).await?;
+        result?;
+
+        Ok((sum + samples as i64 / 2).div_euclid(samples as i64) as i32)
+    }
+
+    /// Measure the internal temperature sensor.
+    ///
+    /// Temperature sensor mode is enabled only for this conversion.
+    #[inline]
+    pub async fn read_temperature<T: DelayNs>(&mut self, delay: &mut T) -> Result<Temperature, Error<I::Error>> {
+        let config = Config {
+            temperature_sensor_mode: true,
+            ..self.config
+        };
+        let sample = self.measure_with_config(&config, delay).await?;
+        Ok(Temperature::from_sample(&sample))
+    }
+
+    /// Measure the analog supply voltage (AVDD - AVSS) in volts.
+    ///
+    /// Uses the `(AVDD - AVSS) / 4` monitor with the PGA bypassed, gain 1 and the internal
+    /// reference, then restores the previous configuration.
+    /// The offset calibration is not applied.
+    #[inline]
+    pub async fn measure_supply_voltage<T: DelayNs>(&mut self, delay: &mut T) -> Result<f32, Error<I::Error>> {
+        self.measure_monitor(Mux::Supply, delay).await
+    }
+
+    /// Measure the external reference voltage (REFP - REFN) in volts.
+    ///
+    /// Uses the `(REFP - REFN) / 4` monitor with the PGA bypassed, gain 1 and the internal
+    /// reference, then restores the previous configuration.
+    /// The offset calibration is not applied.
+    #[inline]
+    pub async fn measure_reference_voltage<T: DelayNs>(&mut self, delay: &mut T) -> Result<f32, Error<I::Error>> {
+        self.measure_monitor(Mux::Vref, delay).await
+    }
+
+    /// Measure the external reference voltage and check it is within `tolerance` volts of `expected`.
+    ///
+    /// Returns the measured voltage and whether it is within the window.
+    pub async fn check_reference_voltage<T: DelayNs>(
+        &mut self,
+        expected: f32,
+        tolerance: f32,
+        delay: &mut T,
+    ) -> Result<(f32, bool), Error<I::Error>> {
+        let volts = self.measure_reference_voltage(delay).await?;
+        Ok((volts, (expected - tolerance..=expected + tolerance).contains(&volts)))
+    }
+
+    /// Measure one of the divide-by-4 monitors against the internal reference
+    async fn measure_monitor<T: DelayNs>(&mut self, mux: Mux, delay: &mut T) -> Result<f32, Error<I::Error>> {
+        let config = Config {
+            mux,
+            gain: Gain::X1,
+            pga_bypass: true,
+            voltage_reference: Vref::Internal,
+            temperature_sensor_mode: false,
+            ..self.config
+        };
+        let sample = self.measure_with_config(&config, delay).await?;
+        // The calibrated offset is for the PGA path, so it does not apply with the PGA bypassed
+        let sample = Sample { code: sample.raw_code(), ..sample };
+        Ok(4.0 * sample.to_volts(Gain::X1, INTERNAL_REFERENCE_UV as f32 * 1e-6))
+    }
+
+    /// Start continuous conversions.
+    ///
+    /// Switches to continuous conversion mode if needed and triggers START/SYNC. Samples are read
+    /// from the returned handle, which waits using `delay` when no DRDY pin is connected.
+    pub async fn start_continuous<T: DelayNs>(&mut self, delay: T) -> Result<Continuous<'_, I, D, T>, Error<I::Error>> {
+        self.set_conversion_mode(ConversionMode::Continuous).await?;
+
+        self.start_sync().await?;
+        Ok(Continuous::new(self, delay))
+    }
+}
+
+impl<I: I2c<SevenBitAddress>, D> ADS122C04<I, D> {
+    /// Create a new device from an I2C peripheral, address and DRDY pin.
+    ///
+    /// The device is assumed to be in its power-on state. Call [`Self::reset`] or
+    /// [`Self::read_config`] if it may have been configured elsewhere.
+    #[inline(always)]
+    pub fn new_with_drdy(i2c: I, address: SevenBitAddress, drdy: D) -> Self {
         Self {
             i2c,
             address,
+            drdy,
+            config: Config::default(),
+            last_count: None,
+            statistics: Statistics::default(),
+            power_down_pending: false,
+            converting: false,
+            external_reference_uv: None,
+            supply_uv: None,
+            calibration: Calibration::default(),
         }
     }
 
     /// Reset the device
     #[inline]
-    pub async fn reset(&mut self) -> Result<(), I::Error> {
-        self.i2c.write(self.address, &[0b0000_0110]).await
+    pub async fn reset(&mut self) -> Result<(), Error<I::Error>> {
+        self.write(&[0b0000_0110]).await?;
+        self.config = Config::default();
+        self.last_count = None;
+        self.converting = false;
+        Ok(())
     }
 
     /// Start or restart conversions
     #[inline]
-    pub async fn start_sync(&mut self) -> Result<(), I::Error> {
-        self.i2c.write(self.address, &[0b0000_1000]).await
+    pub async fn start_sync(&mut self) -> Result<(), Error<I::Error>> {
+        self.write(&[0b0000_1000]).await?;
+        self.last_count = None;
+        self.converting = self.config.conversion_mode == ConversionMode::Continuous;
+        Ok(())
     }
 
     /// Enter power down mode
     #[inline]
-    pub async fn power_down(&mut self) -> Result<(), I::Error> {
-        self.i2c.write(self.address, &[0b0000_0010]).await
+    pub async fn power_down(&mut self) -> Result<(), Error<I::Error>> {
+        self.power_down_pending = false;
+        self.write(&[0b0000_0010]).await?;
+        self.last_count = None;
+        self.converting = false;
+        Ok(())
+    }
+
+    /// The calibration applied to samples
+    #[inline]
+    pub fn calibration(&self) -> &Calibration {
+        &self.calibration
+    }
+
+    /// Replace the calibration applied to samples
+    #[inline]
+    pub fn set_calibration(&mut self, calibration: Calibration) {
+        self.calibration = calibration;
+    }
+
+    /// Set the external reference voltage (REFP - REFN) used for [`Vref::External`]
+    #[inline]
+    pub fn set_external_reference_uv(&mut self, microvolts: u32) {
+        self.external_reference_uv = Some(microvolts);
+    }
+
+    /// Set the analog supply voltage (AVDD - AVSS) used for [`Vref::Supply`] and [`Vref::Supply2`]
+    #[inline]
+    pub fn set_supply_uv(&mut self, microvolts: u32) {
+        self.supply_uv = Some(microvolts);
+    }
+
+    /// The active reference voltage in microvolts, or `None` if it has not been set
+    #[inline]
+    pub fn reference_uv(&self) -> Option<u32> {
+        match self.config.voltage_reference {
+            Vref::Internal => Some(INTERNAL_REFERENCE_UV),
+            Vref::External => self.external_reference_uv,
+            Vref::Supply | Vref::Supply2 => self.supply_uv,
+        }
+    }
+
+    /// Convert a sample to microvolts using the active gain, reference and gain calibration.
+    ///
+    /// The gain setting applies whether or not the PGA is bypassed. Returns `None` if the active
+    /// reference voltage has not been set.
+    #[inline]
+    pub fn to_microvolts(&self, sample: &Sample) -> Option<i32> {
+        let gain = self.config.gain;
+        let microvolts = sample.to_microvolts(gain, self.reference_uv()?);
+        Some(self.calibration.correct_microvolts(gain, microvolts))
+    }
+
+    /// Convert a sample to volts using the active gain, reference and gain calibration.
+    ///
+    /// The gain setting applies whether or not the PGA is bypassed. Returns `None` if the active
+    /// reference voltage has not been set.
+    #[inline]
+    pub fn to_volts(&self, sample: &Sample) -> Option<f32> {
+        let gain = self.config.gain;
+        let volts = sample.to_volts(gain, self.reference_uv()? as f32 * 1e-6);
+        Some(self.calibration.correct(gain, volts))
+    }
+
+    /// Write to the device, first issuing a power down deferred by a dropped [`Continuous`]
+    async fn write(&mut self, bytes: &[u8]) -> Result<(), Error<I::Error>> {
+        self.finish_power_down().await?;
+        self.i2c.write(self.address, bytes).await.map_err(Error::Bus)
+    }
+
+    /// Write then read from the device, first issuing a power down deferred by a dropped [`Continuous`]
+    async fn write_read(&mut self, bytes: &[u8], buffer: &mut [u8]) -> Result<(), Error<I::Error>> {
+        self.finish_power_down().await?;
+        self.i2c.write_read(self.address, bytes, buffer).await.map_err(Error::Bus)
+    }
+
+    #[inline(always)]
+    async fn finish_power_down(&mut self) -> Result<(), Error<I::Error>> {
+        if self.power_down_pending {
+            self.i2c.write(self.address, &[0b0000_0010]).await.map_err(Error::Bus)?;
+            self.power_down_pending = false;
+            self.last_count = None;
+            self.converting = false;
+        }
+        Ok(())
+    }
+
+    /// Data counter statistics accumulated by [`Self::read_sample`]
+    #[inline]
+    pub fn statistics(&self) -> Statistics {
+        self.statistics
+    }
+
+    /// Clear the accumulated data counter statistics
+    #[inline]
+    pub fn reset_statistics(&mut self) {
+        self.statistics = Statistics::default();
     }
 
     /// Read data by command
     #[inline]
-    pub async fn read_data<const N: usize>(&mut self) -> Result<[u8; N], I::Error> {
+    pub async fn read_data<const N: usize>(&mut self) -> Result<[u8; N], Error<I::Error>> {
         let mut out = [0u8; N];
-        self.i2c.write_read(self.address, &[0b0001_0000], &mut out).await?;
+        self.write_read(&[0b0001_0000], &mut out).await?;
         Ok(out)
     }
+
+    /// Read and decode a single conversion result.
+    ///
+    /// The response layout follows the last written or read configuration, so the data
+    /// counter and integrity bytes are handled automatically. The inverted copy or CRC is
+    /// verified when enabled, returning [`Error::InvertedDataMismatch`] or [`Error::Crc`] on
+    /// mismatch.
+    ///
+    /// With the data counter enabled, the sample reports conversions skipped or repeated since
+    /// the previous read, which are also accumulated in [`Self::statistics`].
+    ///
+    /// The calibrated offset for the active gain is subtracted from the code, except in
+    /// temperature sensor mode.
+    #[inline]
+    pub async fn read_sample(&mut self) -> Result<Sample, Error<I::Error>> {
+        let mut frame = [0u8; MAX_FRAME_LEN];
+        let frame = &mut frame[..Sample::frame_len(&self.config)];
+        self.write_read(&[0b0001_0000], frame).await?;
+
+        let mut sample = Sample::from_frame(frame, &self.config)?;
+        self.statistics.record(&mut self.last_count, &mut sample);
+        if !self.config.temperature_sensor_mode {
+            sample.code = sample.code.saturating_sub(self.calibration.offset(self.config.gain));
+        }
+        Ok(sample)
+    }
     
     /// Read the DRDY bit to check for new conversion data
     #[inline]
-    pub async fn read_data_ready(&mut self) -> Result<bool, I::Error> {
+    pub async fn read_data_ready(&mut self) -> Result<bool, Error<I::Error>> {
         let mut out = [0u8; 1];
-        self.i2c.write_read(self.address, &[0b0010_1000], &mut out).await?;
+        self.write_read(&[0b0010_1000], &mut out).await?;
         Ok((out[0] >> 7) != 0)
     }
     
+    /// Poll the DRDY bit over I2C until new conversion data is available.
+    ///
+    /// The poll interval is derived from the active data rate and conversion mode. Returns
+    /// [`Error::Timeout`] if no data arrives within a few conversion periods.
+    pub async fn poll_for_data<T: DelayNs>(&mut self, delay: &mut T) -> Result<(), Error<I::Error>> {
+        let period = self.config.data_rate.period_us();
+        let interval = match self.config.conversion_mode {
+            // A single-shot conversion always takes a full period, so there is no point polling finely
+            ConversionMode::Single => period / 4,
+            ConversionMode::Continuous => period / 8,
+        };
+
+        let mut waited = 0;
+        while !self.read_data_ready().await? {
+            if waited >= POLL_TIMEOUT_PERIODS * period {
+                return Err(Error::Timeout);
+            }
+            delay.delay_us(interval).await;
+            waited += interval;
+        }
+        Ok(())
+    }
+
     /// Write to multiple registers.
     #[inline]
-    pub async fn write_regs<const N: usize>(&mut self, registers: &[Register; N]) -> Result<(), I::Error> {
+    pub async fn write_regs<const N: usize>(&mut self, registers: &[Register; N]) -> Result<(), Error<I::Error>> {
         // Trick to create a `[u8; N*2]` since `N*2` is not stable yet
         let mut cmds = [0u16; N];
         let cmds: &mut [u8] = bytemuck::cast_slice_mut(&mut cmds);
         
-        for (i, &reg) in registers.iter().enumerate() {
-            let (reg, value): (u8, u8) = match reg {
-                Register::Reg0(r) => (0, r.into()),
-                Register::Reg1(r) => (1, r.into()),
-                Register::Reg2(r) => (2, r.into()),
-                Register::Reg3(r) => (3, r.into()),
-            };
-            
-            cmds[2*i] = 0b0100_0000 | (reg << 2);
-            cmds[2*i + 1] = value;
+        for (i, reg) in registers.iter().enumerate() {
+            cmds[2*i] = 0b0100_0000 | (reg.address() << 2);
+            cmds[2*i + 1] = reg.value();
         }
         
-        self.i2c.write(self.address, cmds).await
+        self.write(cmds).await?;
+
+        for reg in registers {
+            self.cache_register(reg);
+        }
+        Ok(())
     }
 
+    /// Update the cached configuration with a register written to or read from the device
     #[inline(always)]
-    async fn read_reg_raw(&mut self, reg: u8) -> Result<u8, I::Error> {
+    fn cache_register(&mut self, register: &Register) {
+        // The counter is meaningless across toggling the data counter
+        if let Register::Reg2(r) = register {
+            if r.data_count_enable != self.config.data_count_enable {
+                self.last_count = None;
+            }
+        }
+        self.config.set_register(register);
+    }
+
+    /// The cached configuration, last written to or read from the device
+    #[inline]
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Write the complete configuration
+    #[inline]
+    pub async fn apply_config(&mut self, config: &Config) -> Result<(), Error<I::Error>> {
+        self.write_regs(&config.registers()).await
+    }
+
+    /// Validate and write the complete configuration.
+    ///
+    /// Returns [`Error::InvalidConfig`] without writing anything if [`Config::validate`] fails.
+    #[inline]
+    pub async fn apply_config_checked(&mut self, config: &Config) -> Result<(), Error<I::Error>> {
+        config.validate().map_err(Error::InvalidConfig)?;
+        self.apply_config(config).await
+    }
+
+    /// Write only the registers that differ from the cached configuration
+    pub async fn update_config(&mut self, config: &Config) -> Result<(), Error<I::Error>> {
+        for (cached, new) in self.config.registers().iter().zip(config.registers()) {
+            if cached.value() != new.value() {
+                self.write_regs(&[new]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Set the input multiplexer
+    #[inline]
+    pub async fn set_mux(&mut self, mux: Mux) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { mux, ..self.config }).await
+    }
+
+    /// Set the gain
+    #[inline]
+    pub async fn set_gain(&mut self, gain: Gain) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { gain, ..self.config }).await
+    }
+
+    /// Set whether the PGA is bypassed
+    #[inline]
+    pub async fn set_pga_bypass(&mut self, pga_bypass: bool) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { pga_bypass, ..self.config }).await
+    }
+
+    /// Set the data rate
+    #[inline]
+    pub async fn set_data_rate(&mut self, data_rate: DataRate) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { data_rate, ..self.config }).await
+    }
+
+    /// Set the conversion mode
+    #[inline]
+    pub async fn set_conversion_mode(&mut self, conversion_mode: ConversionMode) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { conversion_mode, ..self.config }).await
+    }
+
+    /// Set the voltage reference
+    #[inline]
+    pub async fn set_voltage_reference(&mut self, voltage_reference: Vref) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { voltage_reference, ..self.config }).await
+    }
+
+    /// Set the data counter and data integrity mode
+    #[inline]
+    pub async fn set_data_integrity(
+        &mut self,
+        data_count_enable: bool,
+        data_integrity_mode: DataIntegrityMode,
+    ) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { data_count_enable, data_integrity_mode, ..self.config }).await
+    }
+
+    /// Set the IDAC current and the pins IDAC1 and IDAC2 are routed to
+    #[inline]
+    pub async fn set_idac(
+        &mut self,
+        current_dac: CurrentDac,
+        current_mux_1: CurrentMux,
+        current_mux_2: CurrentMux,
+    ) -> Result<(), Error<I::Error>> {
+        self.update_config(&Config { current_dac, current_mux_1, current_mux_2, ..self.config }).await
+    }
+
+    /// Reload the cached configuration from the device
+    #[inline]
+    pub async fn sync_cache(&mut self) -> Result<(), Error<I::Error>> {
+        self.read_config().await.map(|_| ())
+    }
+
+    /// Read the complete configuration
+    pub async fn read_config(&mut self) -> Result<Config, Error<I::Error>> {
+        self.read_reg0().await?;
+        self.read_reg1().await?;
+        self.read_reg2().await?;
+        self.read_reg3().await?;
+        Ok(self.config)
+    }
+
+    /// Write to multiple registers and verify them by reading them back.
+    ///
+    /// The read-only DRDY bit is ignored. On a mismatch the registers are written again, up to
+    /// `retries` times, before returning [`Error::VerifyFailed`] for the first differing register.
+    /// The cached configuration may then be out of date, see [`Self::sync_cache`].
+    pub async fn write_regs_verified<const N: usize>(
+        &mut self,
+        registers: &[Register; N],
+        retries: u8,
+    ) -> Result<(), Error<I::Error>> {
+        let mut attempt = 0;
+        loop {
+            self.write_regs(registers).await?;
+            match self.verify_regs(registers).await {
+                Err(Error::VerifyFailed { .. }) if attempt < retries => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    /// Write the complete configuration and verify it by reading it back.
+    ///
+    /// See [`Self::write_regs_verified`].
+    #[inline]
+    pub async fn apply_config_verified(&mut self, config: &Config, retries: u8) -> Result<(), Error<I::Error>> {
+        self.write_regs_verified(&config.registers(), retries).await
+    }
+
+    async fn verify_regs(&mut self, registers: &[Register]) -> Result<(), Error<I::Error>> {
+        for reg in registers {
+            // Ignore the read-only DRDY bit
+            let mask = if reg.address() == 2 { 0x7F } else { 0xFF };
+            let expected = reg.value() & mask;
+            let actual = self.read_reg_raw(reg.address()).await? & mask;
+            if expected != actual {
+                return Err(Error::VerifyFailed { reg: reg.address(), expected, actual });
+            }
+        }
+        Ok(())
+    }
+
+    #[inline(always)]
+    async fn read_reg_raw(&mut self, reg: u8) -> Result<u8, Error<I::Error>> {
         let cmd = 0b0010_0000 | (reg << 2);
         let mut buf = [0u8; 1];
-        self.i2c.write_read(self.address, &[cmd], &mut buf).await?;
+        self.write_read(&[cmd], &mut buf).await?;
         Ok(buf[0])
     }
 
     /// Read register 0
-    pub async fn read_reg0(&mut self) -> Result<Register0, I::Error> {
-        let value = self.read_reg_raw(0).await?;
-        let mux = value >> 4;
-        let gain = (value >> 1) & 0b111;
-        let pga_bypass = value & 0b1;
-
-        Ok(Register0 {
-            mux: Mux::try_from(mux).unwrap_or(Mux::A0A1),
-            gain: Gain::try_from(gain).unwrap_or(Gain::X1),
-            pga_bypass: pga_bypass != 0,
-        })
+    pub async fn read_reg0(&mut self) -> Result<Register0, Error<I::Error>> {
+        let reg = Register0::try_from(self.read_reg_raw(0).await?)?;
+        self.cache_register(&Register::Reg0(reg));
+        Ok(reg)
     }
 
     /// Read register 1
-    pub async fn read_reg1(&mut self) -> Result<Register1, I::Error> {
-        let value = self.read_reg_raw(1).await?;
-        let dr = (value >> 5) & 0b111;
-        let mode = (value >> 4) != 0;
-        let cm = (value >> 3) & 0b1;
-        let vref = (value >> 1) & 0b11;
-        let ts = value & 0b1;
-
-        Ok(Register1 {
-            data_rate: DataRate::from_dr_mode(dr, mode),
-            conversion_mode: ConversionMode::try_from(cm).unwrap_or(ConversionMode::Single),
-            voltage_reference: Vref::try_from(vref).unwrap_or(Vref::Internal),
-            temperature_sensor_mode: ts != 0,
-        })
+    pub async fn read_reg1(&mut self) -> Result<Register1, Error<I::Error>> {
+        let reg = Register1::try_from(self.read_reg_raw(1).await?)?;
+        self.cache_register(&Register::Reg1(reg));
+        Ok(reg)
     }
 
     /// Read register 2
-    pub async fn read_reg2(&mut self) -> Result<Register2, I::Error> {
-        let value = self.read_reg_raw(2).await?;
-        let drdy = (value >> 7) != 0;
-        let dcnt = (value >> 6) != 0;
-        let crc = (value >> 4) & 0b11;
-        let bcs = (value >> 3) != 0;
-        let idac = value & 0b11;
-
-        Ok(Register2 {
-            data_ready: drdy,
-            data_count_enable: dcnt,
-            data_integrity_mode: DataIntegrityMode::try_from(crc).unwrap_or(DataIntegrityMode::Disabled),
-            burn_out_source_enable: bcs,
-            current_dac: CurrentDac::try_from(idac).unwrap_or(CurrentDac::Off),
-        })
+    pub async fn read_reg2(&mut self) -> Result<Register2, Error<I::Error>> {
+        let reg = Register2::try_from(self.read_reg_raw(2).await?)?;
+        self.cache_register(&Register::Reg2(reg));
+        Ok(reg)
     }
 
     /// Read register 3
-    pub async fn read_reg3(&mut self) -> Result<Register3, I::Error> {
-        let value = self.read_reg_raw(3).await?;
-        let i1mux = (value >> 5) & 0b111;
-        let i2mux = (value >> 2) & 0b111;
-
-        Ok(Register3 {
-            current_mux_1: CurrentMux::try_from(i1mux).unwrap_or(CurrentMux::Disabled),
-            current_mux_2: CurrentMux::try_from(i2mux).unwrap_or(CurrentMux::Disabled),
-        })
+    pub async fn read_reg3(&mut self) -> Result<Register3, Error<I::Error>> {
+        let reg = Register3::try_from(self.read_reg_raw(3).await?)?;
+        self.cache_register(&Register::Reg3(reg));
+        Ok(reg)
     }
 }
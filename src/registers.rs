@@ -3,6 +3,14 @@ use num_enum::TryFromPrimitive;
 #[cfg(feature = "defmt-03")]
 use defmt_03 as defmt;
 
+/// A register value with a reserved bit pattern
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct InvalidRegisterValue {
+    pub reg: u8,
+    pub value: u8,
+}
+
 #[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
@@ -13,7 +21,31 @@ pub enum Register {
     Reg3(Register3) = 3,
 }
 
-#[derive(Copy, Clone, Debug)]
+impl Register {
+    /// The register address
+    #[inline(always)]
+    pub fn address(&self) -> u8 {
+        match self {
+            Register::Reg0(_) => 0,
+            Register::Reg1(_) => 1,
+            Register::Reg2(_) => 2,
+            Register::Reg3(_) => 3,
+        }
+    }
+
+    /// The encoded register value
+    #[inline(always)]
+    pub fn value(&self) -> u8 {
+        match *self {
+            Register::Reg0(r) => r.into(),
+            Register::Reg1(r) => r.into(),
+            Register::Reg2(r) => r.into(),
+            Register::Reg3(r) => r.into(),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub struct Register0 {
     pub mux: Mux,
@@ -31,7 +63,25 @@ impl From<Register0> for u8 {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+impl TryFrom<u8> for Register0 {
+    type Error = InvalidRegisterValue;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let invalid = InvalidRegisterValue { reg: 0, value };
+        let mux = value >> 4;
+        let gain = (value >> 1) & 0b111;
+        let pga_bypass = value & 0b1;
+
+        Ok(Register0 {
+            mux: Mux::try_from(mux).map_err(|_| invalid)?,
+            gain: Gain::try_from(gain).map_err(|_| invalid)?,
+            pga_bypass: pga_bypass != 0,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub struct Register1 {
     pub data_rate: DataRate,
@@ -53,7 +103,28 @@ impl From<Register1> for u8 {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+impl TryFrom<u8> for Register1 {
+    type Error = InvalidRegisterValue;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let invalid = InvalidRegisterValue { reg: 1, value };
+        let dr = value >> 5;
+        let mode = (value >> 4) & 0b1;
+        let cm = (value >> 3) & 0b1;
+        let vref = (value >> 1) & 0b11;
+        let ts = value & 0b1;
+
+        Ok(Register1 {
+            data_rate: DataRate::from_dr_mode(dr, mode != 0).ok_or(invalid)?,
+            conversion_mode: ConversionMode::try_from(cm).map_err(|_| invalid)?,
+            voltage_reference: Vref::try_from(vref).map_err(|_| invalid)?,
+            temperature_sensor_mode: ts != 0,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub struct Register2 {
     pub data_ready: bool,
@@ -76,7 +147,29 @@ impl From<Register2> for u8 {
     }
 }
 
-#[derive(Copy, Clone, Debug)]
+impl TryFrom<u8> for Register2 {
+    type Error = InvalidRegisterValue;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let invalid = InvalidRegisterValue { reg: 2, value };
+        let drdy = value >> 7;
+        let dcnt = (value >> 6) & 0b1;
+        let crc = (value >> 4) & 0b11;
+        let bcs = (value >> 3) & 0b1;
+        let idac = value & 0b111;
+
+        Ok(Register2 {
+            data_ready: drdy != 0,
+            data_count_enable: dcnt != 0,
+            data_integrity_mode: DataIntegrityMode::try_from(crc).map_err(|_| invalid)?,
+            burn_out_source_enable: bcs != 0,
+            current_dac: CurrentDac::try_from(idac).map_err(|_| invalid)?,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 pub struct Register3 {
     pub current_mux_1: CurrentMux,
@@ -92,11 +185,32 @@ impl From<Register3> for u8 {
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+impl TryFrom<u8> for Register3 {
+    type Error = InvalidRegisterValue;
+
+    #[inline]
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        let invalid = InvalidRegisterValue { reg: 3, value };
+        let i1mux = value >> 5;
+        let i2mux = (value >> 2) & 0b111;
+        let reserved = value & 0b11;
+
+        if reserved != 0 {
+            return Err(invalid);
+        }
+        Ok(Register3 {
+            current_mux_1: CurrentMux::try_from(i1mux).map_err(|_| invalid)?,
+            current_mux_2: CurrentMux::try_from(i2mux).map_err(|_| invalid)?,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Mux {
     /// AINp = A0, AINn = A1
+    #[default]
     A0A1 = 0,
     /// AINp = A0, AINn = A2
     A0A2 = 1,
@@ -128,10 +242,11 @@ pub enum Mux {
     Shorted = 14,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Gain {
+    #[default]
     X1 = 0,
     X2 = 1,
     X4 = 2,
@@ -142,10 +257,19 @@ pub enum Gain {
     X128 = 7,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+impl Gain {
+    /// The gain as a multiplication factor
+    #[inline]
+    pub fn factor(&self) -> u8 {
+        1 << (*self as u8)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum DataRate {
+    #[default]
     N20 = 0x00,
     N45 = 0x01,
     N90 = 0x02,
@@ -164,59 +288,89 @@ pub enum DataRate {
 }
 
 impl DataRate {
-    pub(crate) fn from_dr_mode(data_rate: u8, turbo_mode: bool) -> Self {
+    /// Nominal output data rate in samples per second
+    #[inline]
+    pub fn samples_per_second(&self) -> u16 {
+        match self {
+            DataRate::N20 => 20,
+            DataRate::N45 => 45,
+            DataRate::N90 => 90,
+            DataRate::N175 => 175,
+            DataRate::N330 => 330,
+            DataRate::N600 => 600,
+            DataRate::N1000 => 1000,
+            DataRate::T40 => 40,
+            DataRate::T90 => 90,
+            DataRate::T180 => 180,
+            DataRate::T350 => 350,
+            DataRate::T660 => 660,
+            DataRate::T1200 => 1200,
+            DataRate::T2000 => 2000,
+        }
+    }
+
+    /// Nominal conversion period in microseconds
+    #[inline]
+    pub fn period_us(&self) -> u32 {
+        1_000_000u32.div_ceil(self.samples_per_second() as u32)
+    }
+
+    pub(crate) fn from_dr_mode(data_rate: u8, turbo_mode: bool) -> Option<Self> {
         match (turbo_mode, data_rate) {
-            (false, 0x00) => DataRate::N20,
-            (false, 0x01) => DataRate::N45,
-            (false, 0x02) => DataRate::N90,
-            (false, 0x03) => DataRate::N175,
-            (false, 0x04) => DataRate::N330,
-            (false, 0x05) => DataRate::N600,
-            (false, 0x06) => DataRate::N1000,
-            (false, _) => DataRate::N20,
-            (true, 0x00) => DataRate::T40,
-            (true, 0x01) => DataRate::T90,
-            (true, 0x02) => DataRate::T180,
-            (true, 0x03) => DataRate::T350,
-            (true, 0x04) => DataRate::T660,
-            (true, 0x05) => DataRate::T1200,
-            (true, 0x06) => DataRate::T2000,
-            (true, _) => DataRate::T40,
+            (false, 0x00) => Some(DataRate::N20),
+            (false, 0x01) => Some(DataRate::N45),
+            (false, 0x02) => Some(DataRate::N90),
+            (false, 0x03) => Some(DataRate::N175),
+            (false, 0x04) => Some(DataRate::N330),
+            (false, 0x05) => Some(DataRate::N600),
+            (false, 0x06) => Some(DataRate::N1000),
+            (true, 0x00) => Some(DataRate::T40),
+            (true, 0x01) => Some(DataRate::T90),
+            (true, 0x02) => Some(DataRate::T180),
+            (true, 0x03) => Some(DataRate::T350),
+            (true, 0x04) => Some(DataRate::T660),
+            (true, 0x05) => Some(DataRate::T1200),
+            (true, 0x06) => Some(DataRate::T2000),
+            _ => None,
         }
     }
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum ConversionMode {
+    #[default]
     Single = 0,
     Continuous = 1,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum Vref {
+    #[default]
     Internal = 0,
     External = 1,
     Supply = 2,
     Supply2 = 3,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum DataIntegrityMode {
+    #[default]
     Disabled = 0,
     InvertedData = 1,
     Crc16 = 2,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum CurrentDac {
+    #[default]
     Off = 0,
     I10uA = 1,
     I50uA = 2,
@@ -227,10 +381,11 @@ pub enum CurrentDac {
     I1500uA = 7,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, TryFromPrimitive)]
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, TryFromPrimitive)]
 #[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
 #[repr(u8)]
 pub enum CurrentMux {
+    #[default]
     Disabled = 0,
     Ain0 = 1,
     Ain1 = 2,
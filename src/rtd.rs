@@ -0,0 +1,248 @@
+//! RTD measurement using IDAC excitation and a ratiometric reference resistor.
+//!
+//! The IDAC current flows through the RTD and then through a reference resistor between REFP
+//! and REFN, so the conversion result is the ratio of the RTD to the reference resistance,
+//! independent of the exact excitation current.
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{I2c, SevenBitAddress};
+
+use crate::{
+    Calibration, Config, CurrentDac, CurrentMux, DrdyPin, Error, Gain, Mux, Sample, Vref, ADS122C04,
+};
+
+#[cfg(feature = "defmt-03")]
+use defmt_03 as defmt;
+
+/// IEC 60751 Callendar–Van Dusen coefficient A
+const A: f32 = 3.9083e-3;
+/// IEC 60751 Callendar–Van Dusen coefficient B
+const B: f32 = -5.775e-7;
+/// IEC 60751 Callendar–Van Dusen coefficient C, only used below 0 °C
+const C: f32 = -4.183e-12;
+
+/// Lowest temperature covered by the Callendar–Van Dusen equation
+pub const MIN_CELSIUS: f32 = -200.0;
+/// Highest temperature covered by the Callendar–Van Dusen equation
+pub const MAX_CELSIUS: f32 = 850.0;
+
+/// How the RTD is wired to the device
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum Wiring {
+    /// One IDAC, the inputs measure across the RTD including its leads
+    TwoWire,
+    /// Two matched IDACs, one per excitation lead, both returning through the reference resistor
+    ThreeWire,
+    /// One IDAC, the inputs sense directly at the RTD through separate leads
+    FourWire,
+}
+
+/// An RTD connection and its excitation
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Rtd {
+    pub wiring: Wiring,
+    /// Resistance at 0 °C in ohms, 100 for a PT100 or 1000 for a PT1000
+    pub r0: f32,
+    /// Reference resistor between REFP and REFN in ohms
+    pub reference_resistance: f32,
+    /// Inputs measuring across the RTD
+    pub mux: Mux,
+    pub gain: Gain,
+    /// Excitation current of each IDAC
+    pub current: CurrentDac,
+    /// Pin driven by IDAC1
+    pub idac_1: CurrentMux,
+    /// Pin driven by IDAC2, only used with [`Wiring::ThreeWire`]
+    pub idac_2: CurrentMux,
+}
+
+/// The result of an RTD measurement
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct RtdMeasurement {
+    /// RTD resistance in ohms
+    pub resistance: f32,
+    /// RTD temperature in degrees Celsius
+    pub celsius: f32,
+}
+
+/// The result of a three-wire RTD measurement with swapped excitation
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct ThreeWireMeasurement {
+    pub rtd: RtdMeasurement,
+    /// Estimated resistance of a single lead in ohms
+    pub lead_resistance: f32,
+}
+
+impl Rtd {
+    /// The configuration for measuring this RTD, keeping the data rate and data integrity
+    /// settings of `base`
+    pub fn config(&self, base: &Config) -> Config {
+        Config {
+            mux: self.mux,
+            gain: self.gain,
+            pga_bypass: false,
+            voltage_reference: Vref::External,
+            temperature_sensor_mode: false,
+            burn_out_source_enable: false,
+            current_dac: self.current,
+            current_mux_1: self.idac_1,
+            current_mux_2: match self.wiring {
+                Wiring::ThreeWire => self.idac_2,
+                Wiring::TwoWire | Wiring::FourWire => CurrentMux::Disabled,
+            },
+            ..*base
+        }
+    }
+
+    /// The RTD resistance in ohms from a sample taken with [`Self::config`]
+    #[inline]
+    pub fn resistance(&self, sample: &Sample, calibration: &Calibration) -> f32 {
+        // With three-wire wiring both IDAC currents flow through the reference resistor
+        let currents = match self.wiring {
+            Wiring::ThreeWire => 2.0,
+            Wiring::TwoWire | Wiring::FourWire => 1.0,
+        };
+        let ratio = sample.code as f32 / (self.gain.factor() as f32 * 8_388_608.0);
+        calibration.correct(self.gain, ratio) * self.reference_resistance * currents
+    }
+
+    /// Measure the RTD from a sample taken with [`Self::config`]
+    #[inline]
+    pub(crate) fn measurement<E>(&self, sample: &Sample, calibration: &Calibration) -> Result<RtdMeasurement, Error<E>> {
+        if sample.is_saturated() {
+            return Err(Error::OutOfRange);
+        }
+        let resistance = self.resistance(sample, calibration);
+        let celsius = resistance_to_celsius(resistance, self.r0);
+        if !(MIN_CELSIUS..=MAX_CELSIUS).contains(&celsius) {
+            return Err(Error::OutOfRange);
+        }
+        Ok(RtdMeasurement { resistance, celsius })
+    }
+}
+
+/// The Callendar–Van Dusen resistance in ohms at a temperature in degrees Celsius
+#[inline]
+pub fn celsius_to_resistance(celsius: f32, r0: f32) -> f32 {
+    let t = celsius;
+    let c = if t < 0.0 { C * (t - 100.0) * t * t * t } else { 0.0 };
+    r0 * (1.0 + A * t + B * t * t + c)
+}
+
+/// The temperature in degrees Celsius at a resistance in ohms, inverting the Callendar–Van
+/// Dusen equation
+pub fn resistance_to_celsius(resistance: f32, r0: f32) -> f32 {
+    // Newton's method, starting from the linear approximation
+    let mut t = (resistance / r0 - 1.0) / A;
+    for _ in 0..8 {
+        t -= (celsius_to_resistance(t, r0) - resistance) / slope(t, r0);
+    }
+    t
+}
+
+/// The derivative of [`celsius_to_resistance`] in ohms per degree Celsius
+#[inline(always)]
+fn slope(celsius: f32, r0: f32) -> f32 {
+    let t = celsius;
+    let c = if t < 0.0 { C * (4.0 * t - 300.0) * t * t } else { 0.0 };
+    r0 * (A + 2.0 * B * t + c)
+}
+
+impl<I: I2c<SevenBitAddress>, D: DrdyPin> ADS122C04<I, D> {
+    /// Measure an RTD.
+    ///
+    /// Applies the RTD configuration, performs a single-shot ratiometric conversion and restores
+    /// the previous configuration. Returns [`Error::OutOfRange`] if the input saturates or the
+    /// temperature is outside the Callendar–Van Dusen range.
+    pub async fn measure_rtd<T: DelayNs>(&mut self, rtd: &Rtd, delay: &mut T) -> Result<RtdMeasurement, Error<I::Error>> {
+        let config = rtd.config(self.config());
+        let sample = self.measure_with_config(&config, delay).await?;
+        rtd.measurement(&sample, self.calibration())
+    }
+
+    /// Measure a three-wire RTD, cancelling IDAC mismatch by swapping the excitation outputs.
+    ///
+    /// With three-wire wiring, any mismatch between the two IDAC currents shows up as an error
+    /// proportional to the lead resistance. Averaging a reading with the IDAC outputs swapped
+    /// cancels it. A third reading with only IDAC1 enabled includes one lead, giving an estimate
+    /// of the lead resistance. This assumes `idac_1` drives the positive and `idac_2` the negative
+    /// input lead.
+    ///
+    /// `rtd` is measured as [`Wiring::ThreeWire`] regardless of its `wiring`. The device waits
+    /// `settling_us` after each reconfiguration before converting, and the previous
+    /// configuration is restored afterwards.
+    pub async fn measure_rtd_3wire<T: DelayNs>(
+        &mut self,
+        rtd: &Rtd,
+        settling_us: u32,
+        delay: &mut T,
+    ) -> Result<ThreeWireMeasurement, Error<I::Error>> {
+        let rtd = Rtd { wiring: Wiring::ThreeWire, ..*rtd };
+        let swapped = Rtd { idac_1: rtd.idac_2, idac_2: rtd.idac_1, ..rtd };
+        let single = Rtd { wiring: Wiring::TwoWire, ..rtd };
+
+        let previous = *self.config();
+        let converting = self.converting;
+        let mut samples = [Sample::from_bytes([0; 3]); 3];
+        for (sample, setup) in samples.iter_mut().zip([&rtd, &swapped, &single]) {
+            let config = setup.config(&previous);
+            match self.measure_settled(&config, settling_us, delay).await {
+                Ok(s) => *sample = s,
+                Err(e) => {
+                    self.restore_config(&previous, converting).await?;
+                    return Err(e);
+                }
+            }
+        }
+        self.restore_config(&previous, converting).await?;
+
+        let calibration = self.calibration();
+        let a = rtd.measurement(&samples[0], calibration)?;
+        let b = swapped.measurement(&samples[1], calibration)?;
+        if samples[2].is_saturated() {
+            return Err(Error::OutOfRange);
+        }
+        let single = single.resistance(&samples[2], calibration);
+
+        let resistance = (a.resistance + b.resistance) / 2.0;
+        Ok(ThreeWireMeasurement {
+            rtd: RtdMeasurement {
+                resistance,
+                celsius: resistance_to_celsius(resistance, rtd.r0),
+            },
+            lead_resistance: single - resistance,
+        })
+    }
+
+    /// Apply a configuration and wait for the inputs to settle before a single-shot conversion
+    async fn measure_settled<T: DelayNs>(
+        &mut self,
+        config: &Config,
+        settling_us: u32,
+        delay: &mut T,
+    ) -> Result<Sample, Error<I::Error>> {
+        self.update_config(config).await?;
+        delay.delay_us(settling_us).await;
+        self.measure_single(&[], delay).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slope_matches_finite_difference() {
+        for celsius in (-200..=850).step_by(25).map(|t| t as f32) {
+            let h = 5.0;
+            let rise = celsius_to_resistance(celsius + h, 100.0) - celsius_to_resistance(celsius - h, 100.0);
+            let expected = rise / (2.0 * h);
+            let actual = slope(celsius, 100.0);
+            assert!((actual - expected).abs() <= 1e-4 * expected, "{celsius} °C: {actual} Ω/°C");
+        }
+    }
+}
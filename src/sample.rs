@@ -0,0 +1,412 @@
+use crate::crc::crc16;
+use crate::{Config, DataIntegrityMode, Error, Gain};
+
+#[cfg(feature = "defmt-03")]
+use defmt_03 as defmt;
+
+/// Longest RDATA response: counter, data, inverted counter and inverted data
+pub(crate) const MAX_FRAME_LEN: usize = 8;
+
+/// A single conversion result
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Sample {
+    /// The signed conversion code, with any offset calibration applied
+    pub code: i32,
+    /// The raw big-endian conversion bytes, as read from the device
+    pub raw: [u8; 3],
+    /// The conversion counter, if `data_count_enable` is set
+    pub count: Option<u8>,
+    /// Conversions skipped since the previous read, according to the counter
+    pub skipped: u8,
+    /// The counter did not advance since the previous read, so this is a stale re-read
+    pub repeated: bool,
+}
+
+/// Convert a code to microvolts given the gain and reference voltage in microvolts
+#[inline]
+pub(crate) fn code_to_microvolts(code: i32, gain: Gain, reference_uv: u32) -> i32 {
+    let numerator = code as i64 * reference_uv as i64;
+    let denominator = (gain.factor() as i64) << 23;
+    (numerator + denominator / 2).div_euclid(denominator) as i32
+}
+
+/// Data counter statistics accumulated by the driver
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Statistics {
+    /// Samples read
+    pub samples: u32,
+    /// Conversions skipped between reads
+    pub skipped: u32,
+    /// Samples that were stale re-reads of the previous conversion
+    pub repeated: u32,
+}
+
+impl Statistics {
+    /// Update the sample with the gap since `last` and accumulate it
+    pub(crate) fn record(&mut self, last: &mut Option<u8>, sample: &mut Sample) {
+        self.samples = self.samples.wrapping_add(1);
+
+        let Some(count) = sample.count else {
+            return;
+        };
+        if let Some(last) = *last {
+            match count.wrapping_sub(last) {
+                0 => {
+                    sample.repeated = true;
+                    self.repeated = self.repeated.wrapping_add(1);
+                }
+                delta => {
+                    sample.skipped = delta - 1;
+                    self.skipped = self.skipped.wrapping_add(sample.skipped as u32);
+                }
+            }
+        }
+        *last = Some(count);
+    }
+}
+
+impl Sample {
+    /// Largest positive code, reported when the input is at or above positive full scale
+    pub const POSITIVE_FULL_SCALE: i32 = 0x7F_FFFF;
+    /// Most negative code, reported when the input is at or below negative full scale
+    pub const NEGATIVE_FULL_SCALE: i32 = -0x80_0000;
+
+    /// Decode a sample from the three big-endian conversion bytes.
+    #[inline]
+    pub fn from_bytes(raw: [u8; 3]) -> Self {
+        Self {
+            code: Self::decode(raw),
+            raw,
+            count: None,
+            skipped: 0,
+            repeated: false,
+        }
+    }
+
+    /// Length of the RDATA response for the given configuration
+    #[inline]
+    pub(crate) fn frame_len(config: &Config) -> usize {
+        let data = if config.data_count_enable { 4 } else { 3 };
+        match config.data_integrity_mode {
+            DataIntegrityMode::Disabled => data,
+            DataIntegrityMode::InvertedData => 2 * data,
+            DataIntegrityMode::Crc16 => data + 2,
+        }
+    }
+
+    /// Decode and verify a RDATA response laid out according to the configuration
+    pub(crate) fn from_frame<E>(frame: &[u8], config: &Config) -> Result<Self, Error<E>> {
+        let data_len = if config.data_count_enable { 4 } else { 3 };
+        let (payload, check) = frame.split_at(data_len);
+
+        match config.data_integrity_mode {
+            DataIntegrityMode::Disabled => {}
+            DataIntegrityMode::InvertedData => {
+                if payload.iter().zip(check).any(|(&d, &i)| d != !i) {
+                    return Err(Error::InvertedDataMismatch);
+                }
+            }
+            DataIntegrityMode::Crc16 => {
+                if crc16(payload) != u16::from_be_bytes([check[0], check[1]]) {
+                    return Err(Error::Crc);
+                }
+            }
+        }
+
+        let (count, data) = if config.data_count_enable {
+            (Some(payload[0]), &payload[1..])
+        } else {
+            (None, payload)
+        };
+
+        Ok(Self {
+            count,
+            ..Self::from_bytes([data[0], data[1], data[2]])
+        })
+    }
+
+    /// Convert to microvolts given the gain and reference voltage in microvolts
+    #[inline]
+    pub fn to_microvolts(&self, gain: Gain, reference_uv: u32) -> i32 {
+        code_to_microvolts(self.code, gain, reference_uv)
+    }
+
+    /// Convert to volts given the gain and reference voltage in volts
+    #[inline]
+    pub fn to_volts(&self, gain: Gain, reference: f32) -> f32 {
+        self.code as f32 * reference / (gain.factor() as f32 * 8_388_608.0)
+    }
+
+    /// The code decoded from the raw bytes, before any calibration
+    #[inline]
+    pub fn raw_code(&self) -> i32 {
+        Self::decode(self.raw)
+    }
+
+    /// Sign-extend the 24-bit two's complement conversion bytes
+    #[inline(always)]
+    fn decode(raw: [u8; 3]) -> i32 {
+        i32::from_be_bytes([raw[0], raw[1], raw[2], 0]) >> 8
+    }
+
+    /// The raw code is clipped at positive full scale
+    #[inline]
+    pub fn is_positive_full_scale(&self) -> bool {
+        self.raw_code() == Self::POSITIVE_FULL_SCALE
+    }
+
+    /// The raw code is clipped at negative full scale
+    #[inline]
+    pub fn is_negative_full_scale(&self) -> bool {
+        self.raw_code() == Self::NEGATIVE_FULL_SCALE
+    }
+
+    /// The raw code is clipped at either full scale
+    #[inline]
+    pub fn is_saturated(&self) -> bool {
+        self.is_positive_full_scale() || self.is_negative_full_scale()
+    }
+}
+
+/// An internal temperature sensor reading
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Temperature {
+    /// The 14-bit two's complement temperature code, 0.03125 °C per LSB
+    pub code: i16,
+}
+
+impl Temperature {
+    /// Decode the left-justified 14-bit result of a temperature sensor conversion
+    #[inline]
+    pub fn from_sample(sample: &Sample) -> Self {
+        Self {
+            code: (sample.raw_code() >> 10) as i16,
+        }
+    }
+
+    /// The temperature in degrees Celsius
+    #[inline]
+    pub fn celsius(&self) -> f32 {
+        self.code as f32 * 0.03125
+    }
+
+    /// The temperature in thousandths of a degree Celsius
+    #[inline]
+    pub fn millicelsius(&self) -> i32 {
+        self.code as i32 * 125 / 4
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crc_config(data_count_enable: bool) -> Config {
+        Config {
+            data_count_enable,
+            data_integrity_mode: DataIntegrityMode::Crc16,
+            ..Config::default()
+        }
+    }
+
+    /// Append the CRC16 of `payload` to form a frame
+    fn crc_frame(payload: &[u8]) -> [u8; MAX_FRAME_LEN] {
+        let mut frame = [0u8; MAX_FRAME_LEN];
+        frame[..payload.len()].copy_from_slice(payload);
+        frame[payload.len()..payload.len() + 2].copy_from_slice(&crc16(payload).to_be_bytes());
+        frame
+    }
+
+    #[test]
+    fn crc_frame_valid() {
+        let config = crc_config(false);
+        let frame = crc_frame(&[0x12, 0x34, 0x56]);
+        let sample = Sample::from_frame::<()>(&frame[..Sample::frame_len(&config)], &config).unwrap();
+        assert_eq!(sample.code, 0x12_3456);
+        assert_eq!(sample.count, None);
+    }
+
+    #[test]
+    fn crc_frame_valid_with_count() {
+        let config = crc_config(true);
+        let frame = crc_frame(&[0x07, 0xFF, 0xFF, 0xFE]);
+        let sample = Sample::from_frame::<()>(&frame[..Sample::frame_len(&config)], &config).unwrap();
+        assert_eq!(sample.code, -2);
+        assert_eq!(sample.count, Some(0x07));
+    }
+
+    #[test]
+    fn crc_frame_corrupted() {
+        for data_count_enable in [false, true] {
+            let config = crc_config(data_count_enable);
+            let len = Sample::frame_len(&config);
+            let payload: &[u8] = if data_count_enable { &[0x07, 0x12, 0x34, 0x56] } else { &[0x12, 0x34, 0x56] };
+            let frame = crc_frame(payload);
+            for byte in 0..len {
+                let mut corrupted = frame;
+                corrupted[byte] ^= 0x01;
+                assert_eq!(
+                    Sample::from_frame::<()>(&corrupted[..len], &config),
+                    Err(Error::Crc),
+                    "data_count_enable {data_count_enable} byte {byte}"
+                );
+            }
+        }
+    }
+
+    fn inverted_config(data_count_enable: bool) -> Config {
+        Config {
+            data_count_enable,
+            data_integrity_mode: DataIntegrityMode::InvertedData,
+            ..Config::default()
+        }
+    }
+
+    /// Append the inverted copy of `payload` to form a frame
+    fn inverted_frame(payload: &[u8]) -> [u8; MAX_FRAME_LEN] {
+        let mut frame = [0u8; MAX_FRAME_LEN];
+        frame[..payload.len()].copy_from_slice(payload);
+        for (inverted, &byte) in frame[payload.len()..].iter_mut().zip(payload) {
+            *inverted = !byte;
+        }
+        frame
+    }
+
+    #[test]
+    fn inverted_frame_valid() {
+        let config = inverted_config(false);
+        let frame = inverted_frame(&[0x12, 0x34, 0x56]);
+        assert_eq!(Sample::frame_len(&config), 6);
+        let sample = Sample::from_frame::<()>(&frame[..6], &config).unwrap();
+        assert_eq!(sample.code, 0x12_3456);
+        assert_eq!(sample.count, None);
+    }
+
+    #[test]
+    fn inverted_frame_valid_with_count() {
+        let config = inverted_config(true);
+        let frame = inverted_frame(&[0x07, 0xFF, 0xFF, 0xFE]);
+        assert_eq!(Sample::frame_len(&config), 8);
+        let sample = Sample::from_frame::<()>(&frame[..8], &config).unwrap();
+        assert_eq!(sample.code, -2);
+        assert_eq!(sample.count, Some(0x07));
+    }
+
+    #[test]
+    fn inverted_frame_corrupted() {
+        for data_count_enable in [false, true] {
+            let config = inverted_config(data_count_enable);
+            let len = Sample::frame_len(&config);
+            let payload: &[u8] = if data_count_enable { &[0x07, 0x12, 0x34, 0x56] } else { &[0x12, 0x34, 0x56] };
+            let frame = inverted_frame(payload);
+            for bit in 0..8 * len {
+                let mut corrupted = frame;
+                corrupted[bit / 8] ^= 1 << (bit % 8);
+                assert_eq!(
+                    Sample::from_frame::<()>(&corrupted[..len], &config),
+                    Err(Error::InvertedDataMismatch),
+                    "data_count_enable {data_count_enable} bit {bit}"
+                );
+            }
+        }
+    }
+
+    /// Record a sample with the given counter, returning its skipped and repeated fields
+    fn record(statistics: &mut Statistics, last: &mut Option<u8>, count: Option<u8>) -> (u8, bool) {
+        let mut sample = Sample { count, ..Sample::from_bytes([0; 3]) };
+        statistics.record(last, &mut sample);
+        (sample.skipped, sample.repeated)
+    }
+
+    #[test]
+    fn statistics_step() {
+        let mut statistics = Statistics::default();
+        let mut last = None;
+        assert_eq!(record(&mut statistics, &mut last, Some(10)), (0, false));
+        assert_eq!(record(&mut statistics, &mut last, Some(11)), (0, false));
+        assert_eq!(last, Some(11));
+        assert_eq!(statistics, Statistics { samples: 2, skipped: 0, repeated: 0 });
+    }
+
+    #[test]
+    fn statistics_skipped() {
+        let mut statistics = Statistics::default();
+        let mut last = Some(10);
+        assert_eq!(record(&mut statistics, &mut last, Some(14)), (3, false));
+        assert_eq!(record(&mut statistics, &mut last, Some(16)), (1, false));
+        assert_eq!(statistics, Statistics { samples: 2, skipped: 4, repeated: 0 });
+    }
+
+    #[test]
+    fn statistics_repeated() {
+        let mut statistics = Statistics::default();
+        let mut last = Some(10);
+        assert_eq!(record(&mut statistics, &mut last, Some(10)), (0, true));
+        assert_eq!(last, Some(10));
+        assert_eq!(statistics, Statistics { samples: 1, skipped: 0, repeated: 1 });
+    }
+
+    #[test]
+    fn statistics_wrap_around() {
+        let mut statistics = Statistics::default();
+        let mut last = Some(0xFF);
+        assert_eq!(record(&mut statistics, &mut last, Some(0x00)), (0, false));
+        let mut last = Some(0xFE);
+        assert_eq!(record(&mut statistics, &mut last, Some(0x01)), (2, false));
+        assert_eq!(statistics, Statistics { samples: 2, skipped: 2, repeated: 0 });
+    }
+
+    #[test]
+    fn statistics_without_count() {
+        let mut statistics = Statistics::default();
+        let mut last = Some(10);
+        assert_eq!(record(&mut statistics, &mut last, None), (0, false));
+        assert_eq!(last, Some(10));
+        assert_eq!(statistics, Statistics { samples: 1, skipped: 0, repeated: 0 });
+    }
+
+    /// A temperature sensor sample holding a 14-bit code, left-justified in the 24-bit result
+    fn temperature(code: u16, low_bits: u16) -> Temperature {
+        let raw = ((code as u32) << 10 | low_bits as u32).to_be_bytes();
+        Temperature::from_sample(&Sample::from_bytes([raw[1], raw[2], raw[3]]))
+    }
+
+    #[test]
+    fn temperature_table() {
+        // Datasheet temperature data format table, 14-bit codes
+        for (code, celsius, millicelsius) in [
+            (0x1000, 128.0, 128_000),
+            (0x0FFF, 127.96875, 127_968),
+            (0x0C80, 100.0, 100_000),
+            (0x0320, 25.0, 25_000),
+            (0x0008, 0.25, 250),
+            (0x0001, 0.03125, 31),
+            (0x0000, 0.0, 0),
+            (0x3FF8, -0.25, -250),
+            (0x3CE0, -25.0, -25_000),
+            (0x3B00, -40.0, -40_000),
+        ] {
+            for low_bits in [0, 0x3FF] {
+                let temperature = temperature(code, low_bits);
+                assert_eq!(temperature.celsius(), celsius, "code {code:#06x}");
+                assert_eq!(temperature.millicelsius(), millicelsius, "code {code:#06x}");
+            }
+        }
+    }
+
+    #[test]
+    fn temperature_full_scale() {
+        let positive = temperature(0x1FFF, 0);
+        assert_eq!(positive.code, 0x1FFF);
+        assert_eq!(positive.celsius(), 255.96875);
+        assert_eq!(positive.millicelsius(), 255_968);
+
+        let negative = temperature(0x2000, 0);
+        assert_eq!(negative.code, -0x2000);
+        assert_eq!(negative.celsius(), -256.0);
+        assert_eq!(negative.millicelsius(), -256_000);
+    }
+}
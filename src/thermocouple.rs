@@ -0,0 +1,949 @@
+//! Thermocouple measurement with internal cold-junction compensation.
+//!
+//! The junction voltage is measured differentially against the internal reference, and the
+//! internal temperature sensor gives the cold-junction temperature. Voltages and temperatures
+//! are related by the NIST ITS-90 thermocouple polynomials.
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::{I2c, SevenBitAddress};
+
+use crate::{Config, DrdyPin, Error, Gain, Mux, Vref, ADS122C04, INTERNAL_REFERENCE_UV};
+
+#[cfg(feature = "defmt-03")]
+use defmt_03 as defmt;
+
+/// Thermocouple type
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub enum Type {
+    B,
+    E,
+    J,
+    K,
+    N,
+    R,
+    S,
+    T,
+}
+
+/// A thermocouple connection
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct Thermocouple {
+    pub kind: Type,
+    /// Inputs the thermocouple is connected across, positive leg on AINp
+    pub mux: Mux,
+    /// Gain, chosen so the junction voltage stays within `±2.048 V / gain`
+    pub gain: Gain,
+}
+
+/// The result of a thermocouple measurement
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "defmt-03", derive(defmt::Format))]
+pub struct ThermocoupleMeasurement {
+    /// Hot-junction temperature in degrees Celsius
+    pub celsius: f32,
+    /// Cold-junction temperature from the internal temperature sensor in degrees Celsius
+    pub cold_junction_celsius: f32,
+    /// Measured junction voltage in millivolts, before cold-junction compensation
+    pub millivolts: f32,
+}
+
+/// A polynomial valid from the upper bound of the previous segment up to `max`
+struct Segment {
+    max: f32,
+    coefficients: &'static [f64],
+}
+
+/// Piecewise polynomial valid from `min` up to the `max` of the last segment.
+///
+/// The outer limits of the inverse tables are widened by 1 µV over the NIST ranges, which are
+/// rounded to 1 µV, so the voltage at each end of the temperature range converts back.
+struct Table {
+    min: f32,
+    segments: &'static [Segment],
+}
+
+impl Table {
+    fn evaluate(&self, x: f32) -> Option<f64> {
+        if x < self.min {
+            return None;
+        }
+        let segment = self.segments.iter().find(|s| x <= s.max)?;
+        let x = x as f64;
+        Some(segment.coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c))
+    }
+}
+
+/// Type K exponential term `a0 * exp(a1 * (t - a2)^2)` above 0 °C
+const K_EXPONENTIAL: [f64; 3] = [1.185976e-1, -1.183432e-4, 1.269686e2];
+
+/// Temperature to millivolts
+const FORWARD_B: Table = Table {
+    min: 0.0,
+    segments: &[
+        Segment {
+            max: 630.615,
+            coefficients: &[
+                0.0,
+                -2.4650818346e-4,
+                5.9040421171e-6,
+                -1.3257931636e-9,
+                1.5668291901e-12,
+                -1.6944529240e-15,
+                6.2990347094e-19,
+            ],
+        },
+        Segment {
+            max: 1820.0,
+            coefficients: &[
+                -3.8938168621,
+                2.8571747470e-2,
+                -8.4885104785e-5,
+                1.5785280164e-7,
+                -1.6835344864e-10,
+                1.1109794013e-13,
+                -4.4515431033e-17,
+                9.8975640821e-21,
+                -9.3791330289e-25,
+            ],
+        },
+    ],
+};
+
+/// Millivolts to temperature
+const INVERSE_B: Table = Table {
+    min: 0.290,
+    segments: &[
+        Segment {
+            max: 2.431,
+            coefficients: &[
+                9.8423321e1,
+                6.9971500e2,
+                -8.4765304e2,
+                1.0052644e3,
+                -8.3345952e2,
+                4.5508542e2,
+                -1.5523037e2,
+                2.9886750e1,
+                -2.4742860,
+            ],
+        },
+        Segment {
+            max: 13.821,
+            coefficients: &[
+                2.1315071e2,
+                2.8510504e2,
+                -5.2742887e1,
+                9.9160804,
+                -1.2965303,
+                1.1195870e-1,
+                -6.0625199e-3,
+                1.8661696e-4,
+                -2.4878585e-6,
+            ],
+        },
+    ],
+};
+
+const FORWARD_E: Table = Table {
+    min: -270.0,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                5.8665508708e-2,
+                4.5410977124e-5,
+                -7.7998048686e-7,
+                -2.5800160843e-8,
+                -5.9452583057e-10,
+                -9.3214058667e-12,
+                -1.0287605534e-13,
+                -8.0370123621e-16,
+                -4.3979497391e-18,
+                -1.6414776355e-20,
+                -3.9673619516e-23,
+                -5.5827328721e-26,
+                -3.4657842013e-29,
+            ],
+        },
+        Segment {
+            max: 1000.0,
+            coefficients: &[
+                0.0,
+                5.8665508710e-2,
+                4.5032275582e-5,
+                2.8908407212e-8,
+                -3.3056896652e-10,
+                6.5024403270e-13,
+                -1.9197495504e-16,
+                -1.2536600497e-18,
+                2.1489217569e-21,
+                -1.4388041782e-24,
+                3.5960899481e-28,
+            ],
+        },
+    ],
+};
+
+const INVERSE_E: Table = Table {
+    min: -8.826,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                1.6977288e1,
+                -4.3514970e-1,
+                -1.5859697e-1,
+                -9.2502871e-2,
+                -2.6084314e-2,
+                -4.1360199e-3,
+                -3.4034030e-4,
+                -1.1564890e-5,
+            ],
+        },
+        Segment {
+            max: 76.374,
+            coefficients: &[
+                0.0,
+                1.7057035e1,
+                -2.3301759e-1,
+                6.5435585e-3,
+                -7.3562749e-5,
+                -1.7896001e-6,
+                8.4036165e-8,
+                -1.3735879e-9,
+                1.0629823e-11,
+                -3.2447087e-14,
+            ],
+        },
+    ],
+};
+
+const FORWARD_J: Table = Table {
+    min: -210.0,
+    segments: &[
+        Segment {
+            max: 760.0,
+            coefficients: &[
+                0.0,
+                5.0381187815e-2,
+                3.0475836930e-5,
+                -8.5681065720e-8,
+                1.3228195295e-10,
+                -1.7052958337e-13,
+                2.0948090697e-16,
+                -1.2538395336e-19,
+                1.5631725697e-23,
+            ],
+        },
+        Segment {
+            max: 1200.0,
+            coefficients: &[
+                2.9645625681e2,
+                -1.4976127786,
+                3.1787103924e-3,
+                -3.1847686701e-6,
+                1.5720819004e-9,
+                -3.0691369056e-13,
+            ],
+        },
+    ],
+};
+
+const INVERSE_J: Table = Table {
+    min: -8.096,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                1.9528268e1,
+                -1.2286185,
+                -1.0752178,
+                -5.9086933e-1,
+                -1.7256713e-1,
+                -2.8131513e-2,
+                -2.3963370e-3,
+                -8.3823321e-5,
+            ],
+        },
+        Segment {
+            max: 42.919,
+            coefficients: &[
+                0.0,
+                1.978425e1,
+                -2.001204e-1,
+                1.036969e-2,
+                -2.549687e-4,
+                3.585153e-6,
+                -5.344285e-8,
+                5.099890e-10,
+            ],
+        },
+        Segment {
+            max: 69.554,
+            coefficients: &[
+                -3.11358187e3,
+                3.00543684e2,
+                -9.94773230,
+                1.70276630e-1,
+                -1.43033468e-3,
+                4.73886084e-6,
+            ],
+        },
+    ],
+};
+
+const FORWARD_K: Table = Table {
+    min: -270.0,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                3.9450128025e-2,
+                2.3622373598e-5,
+                -3.2858906784e-7,
+                -4.9904828777e-9,
+                -6.7509059173e-11,
+                -5.7410327428e-13,
+                -3.1088872894e-15,
+                -1.0451609365e-17,
+                -1.9889266878e-20,
+                -1.6322697486e-23,
+            ],
+        },
+        Segment {
+            max: 1372.0,
+            coefficients: &[
+                -1.7600413686e-2,
+                3.8921204975e-2,
+                1.8558770032e-5,
+                -9.9457592874e-8,
+                3.1840945719e-10,
+                -5.6072844889e-13,
+                5.6075059059e-16,
+                -3.2020720003e-19,
+                9.7151147152e-23,
+                -1.2104721275e-26,
+            ],
+        },
+    ],
+};
+
+const INVERSE_K: Table = Table {
+    min: -5.892,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                2.5173462e1,
+                -1.1662878,
+                -1.0833638,
+                -8.9773540e-1,
+                -3.7342377e-1,
+                -8.6632643e-2,
+                -1.0450598e-2,
+                -5.1920577e-4,
+            ],
+        },
+        Segment {
+            max: 20.644,
+            coefficients: &[
+                0.0,
+                2.508355e1,
+                7.860106e-2,
+                -2.503131e-1,
+                8.315270e-2,
+                -1.228034e-2,
+                9.804036e-4,
+                -4.413030e-5,
+                1.057734e-6,
+                -1.052755e-8,
+            ],
+        },
+        Segment {
+            max: 54.887,
+            coefficients: &[
+                -1.318058e2,
+                4.830222e1,
+                -1.646031,
+                5.464731e-2,
+                -9.650715e-4,
+                8.802193e-6,
+                -3.110810e-8,
+            ],
+        },
+    ],
+};
+
+const FORWARD_N: Table = Table {
+    min: -270.0,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                2.6159105962e-2,
+                1.0957484228e-5,
+                -9.3841111554e-8,
+                -4.6412039759e-11,
+                -2.6303357716e-12,
+                -2.2653438003e-14,
+                -7.6089300791e-17,
+                -9.3419667835e-20,
+            ],
+        },
+        Segment {
+            max: 1300.0,
+            coefficients: &[
+                0.0,
+                2.5929394601e-2,
+                1.5710141880e-5,
+                4.3825627237e-8,
+                -2.5261169794e-10,
+                6.4311819339e-13,
+                -1.0063471519e-15,
+                9.9745338992e-19,
+                -6.0863245607e-22,
+                2.0849229339e-25,
+                -3.0682196151e-29,
+            ],
+        },
+    ],
+};
+
+const INVERSE_N: Table = Table {
+    min: -3.991,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                3.8436847e1,
+                1.1010485,
+                5.2229312,
+                7.2060525,
+                5.8488586,
+                2.7754916,
+                7.7075166e-1,
+                1.1582665e-1,
+                7.3138868e-3,
+            ],
+        },
+        Segment {
+            max: 20.613,
+            coefficients: &[
+                0.0,
+                3.86896e1,
+                -1.08267,
+                4.70205e-2,
+                -2.12169e-6,
+                -1.17272e-4,
+                5.39280e-6,
+                -7.98156e-8,
+            ],
+        },
+        Segment {
+            max: 47.514,
+            coefficients: &[
+                1.972485e1,
+                3.300943e1,
+                -3.915159e-1,
+                9.855391e-3,
+                -1.274371e-4,
+                7.767022e-7,
+            ],
+        },
+    ],
+};
+
+const FORWARD_R: Table = Table {
+    min: -50.0,
+    segments: &[
+        Segment {
+            max: 1064.18,
+            coefficients: &[
+                0.0,
+                5.28961729765e-3,
+                1.39166589782e-5,
+                -2.38855693017e-8,
+                3.56916001063e-11,
+                -4.62347666298e-14,
+                5.00777441034e-17,
+                -3.73105886191e-20,
+                1.57716482367e-23,
+                -2.81038625251e-27,
+            ],
+        },
+        Segment {
+            max: 1664.5,
+            coefficients: &[
+                2.95157925316,
+                -2.52061251332e-3,
+                1.59564501865e-5,
+                -7.64085947576e-9,
+                2.05305291024e-12,
+                -2.93359668173e-16,
+            ],
+        },
+        Segment {
+            max: 1768.1,
+            coefficients: &[
+                1.52232118209e2,
+                -2.68819888545e-1,
+                1.71280280471e-4,
+                -3.45895706453e-8,
+                -9.34633971046e-15,
+            ],
+        },
+    ],
+};
+
+const INVERSE_R: Table = Table {
+    min: -0.227,
+    segments: &[
+        Segment {
+            max: 1.923,
+            coefficients: &[
+                0.0,
+                1.8891380e2,
+                -9.3835290e1,
+                1.3068619e2,
+                -2.2703580e2,
+                3.5145659e2,
+                -3.8953900e2,
+                2.8239471e2,
+                -1.2607281e2,
+                3.1353611e1,
+                -3.3187769,
+            ],
+        },
+        Segment {
+            max: 11.361,
+            coefficients: &[
+                1.334584505e1,
+                1.472644573e2,
+                -1.844024844e1,
+                4.031129726,
+                -6.249428360e-1,
+                6.468412046e-2,
+                -4.458750426e-3,
+                1.994710149e-4,
+                -5.313401790e-6,
+                6.481976217e-8,
+            ],
+        },
+        Segment {
+            max: 19.739,
+            coefficients: &[
+                -8.199599416e1,
+                1.553962042e2,
+                -8.342197663,
+                4.279433549e-1,
+                -1.191577910e-2,
+                1.492290091e-4,
+            ],
+        },
+        Segment {
+            max: 21.104,
+            coefficients: &[
+                3.406177836e4,
+                -7.023729171e3,
+                5.582903813e2,
+                -1.952394635e1,
+                2.560740231e-1,
+            ],
+        },
+    ],
+};
+
+const FORWARD_S: Table = Table {
+    min: -50.0,
+    segments: &[
+        Segment {
+            max: 1064.18,
+            coefficients: &[
+                0.0,
+                5.40313308631e-3,
+                1.25934289740e-5,
+                -2.32477968689e-8,
+                3.22028823036e-11,
+                -3.31465196389e-14,
+                2.55744251786e-17,
+                -1.25068871393e-20,
+                2.71443176145e-24,
+            ],
+        },
+        Segment {
+            max: 1664.5,
+            coefficients: &[
+                1.32900444085,
+                3.34509311344e-3,
+                6.54805192818e-6,
+                -1.64856259209e-9,
+                1.29989605174e-14,
+            ],
+        },
+        Segment {
+            max: 1768.1,
+            coefficients: &[
+                1.46628232636e2,
+                -2.58430516752e-1,
+                1.63693574641e-4,
+                -3.30439046987e-8,
+                -9.43223690612e-15,
+            ],
+        },
+    ],
+};
+
+const INVERSE_S: Table = Table {
+    min: -0.236,
+    segments: &[
+        Segment {
+            max: 1.874,
+            coefficients: &[
+                0.0,
+                1.84949460e2,
+                -8.00504062e1,
+                1.02237430e2,
+                -1.52248592e2,
+                1.88821343e2,
+                -1.59085941e2,
+                8.23027880e1,
+                -2.34181944e1,
+                2.79786260,
+            ],
+        },
+        Segment {
+            max: 10.332,
+            coefficients: &[
+                1.291507177e1,
+                1.466298863e2,
+                -1.534713402e1,
+                3.145945973,
+                -4.163257839e-1,
+                3.187963771e-2,
+                -1.291637500e-3,
+                2.183475087e-5,
+                -1.447379511e-7,
+                8.211272125e-9,
+            ],
+        },
+        Segment {
+            max: 17.536,
+            coefficients: &[
+                -8.087801117e1,
+                1.621573104e2,
+                -8.536869453,
+                4.719686976e-1,
+                -1.441693666e-2,
+                2.081618890e-4,
+            ],
+        },
+        Segment {
+            max: 18.694,
+            coefficients: &[
+                5.333875126e4,
+                -1.235892298e4,
+                1.092657613e3,
+                -4.265693686e1,
+                6.247205420e-1,
+            ],
+        },
+    ],
+};
+
+const FORWARD_T: Table = Table {
+    min: -270.0,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                3.8748106364e-2,
+                4.4194434347e-5,
+                1.1844323105e-7,
+                2.0032973554e-8,
+                9.0138019559e-10,
+                2.2651156593e-11,
+                3.6071154205e-13,
+                3.8493939883e-15,
+                2.8213521925e-17,
+                1.4251594779e-19,
+                4.8768662286e-22,
+                1.0795539270e-24,
+                1.3945027062e-27,
+                7.9795153927e-31,
+            ],
+        },
+        Segment {
+            max: 400.0,
+            coefficients: &[
+                0.0,
+                3.8748106364e-2,
+                3.3292227880e-5,
+                2.0618243404e-7,
+                -2.1882256846e-9,
+                1.0996880928e-11,
+                -3.0815758772e-14,
+                4.5479135290e-17,
+                -2.7512901673e-20,
+            ],
+        },
+    ],
+};
+
+const INVERSE_T: Table = Table {
+    min: -5.604,
+    segments: &[
+        Segment {
+            max: 0.0,
+            coefficients: &[
+                0.0,
+                2.5949192e1,
+                -2.1316967e-1,
+                7.9018692e-1,
+                4.2527777e-1,
+                1.3304473e-1,
+                2.0241446e-2,
+                1.2668171e-3,
+            ],
+        },
+        Segment {
+            max: 20.873,
+            coefficients: &[
+                0.0,
+                2.592800e1,
+                -7.602961e-1,
+                4.637791e-2,
+                -2.165394e-3,
+                6.048144e-5,
+                -7.293422e-7,
+            ],
+        },
+    ],
+};
+
+/// `e^x` for `x <= 0`, since `core` has no `exp`
+fn exp_negative(x: f64) -> f64 {
+    if x < -50.0 {
+        return 0.0;
+    }
+    // Taylor series on a small argument, then square back up
+    let y = x / 256.0;
+    let mut result = 1.0 + y * (1.0 + y / 2.0 * (1.0 + y / 3.0 * (1.0 + y / 4.0 * (1.0 + y / 5.0))));
+    for _ in 0..8 {
+        result *= result;
+    }
+    result
+}
+
+impl Type {
+    #[inline(always)]
+    fn tables(&self) -> (&'static Table, &'static Table) {
+        match self {
+            Type::B => (&FORWARD_B, &INVERSE_B),
+            Type::E => (&FORWARD_E, &INVERSE_E),
+            Type::J => (&FORWARD_J, &INVERSE_J),
+            Type::K => (&FORWARD_K, &INVERSE_K),
+            Type::N => (&FORWARD_N, &INVERSE_N),
+            Type::R => (&FORWARD_R, &INVERSE_R),
+            Type::S => (&FORWARD_S, &INVERSE_S),
+            Type::T => (&FORWARD_T, &INVERSE_T),
+        }
+    }
+
+    /// The thermoelectric voltage in millivolts at a temperature in degrees Celsius, relative
+    /// to a 0 °C reference junction. Returns `None` outside the NIST reference range.
+    pub fn millivolts(&self, celsius: f32) -> Option<f32> {
+        let mut emf = self.tables().0.evaluate(celsius)?;
+        let t = celsius as f64;
+        if *self == Type::K && t > 0.0 {
+            let [a0, a1, a2] = K_EXPONENTIAL;
+            emf += a0 * exp_negative(a1 * (t - a2) * (t - a2));
+        }
+        Some(emf as f32)
+    }
+
+    /// The temperature in degrees Celsius at a thermoelectric voltage in millivolts, relative
+    /// to a 0 °C reference junction. Returns `None` outside the NIST inverse range.
+    pub fn celsius(&self, millivolts: f32) -> Option<f32> {
+        self.tables().1.evaluate(millivolts).map(|t| t as f32)
+    }
+}
+
+impl<I: I2c<SevenBitAddress>, D: DrdyPin> ADS122C04<I, D> {
+    /// Measure a thermocouple, compensating the cold junction with the internal temperature sensor.
+    ///
+    /// The junction voltage is measured against the internal reference with the PGA enabled and
+    /// the previous configuration is restored afterwards. Returns [`Error::OutOfRange`] if the
+    /// input saturates or either temperature is outside the NIST range for the type.
+    pub async fn measure_thermocouple<T: DelayNs>(
+        &mut self,
+        thermocouple: &Thermocouple,
+        delay: &mut T,
+    ) -> Result<ThermocoupleMeasurement, Error<I::Error>> {
+        let cold_junction = self.read_temperature(delay).await?.celsius();
+
+        let config = Config {
+            mux: thermocouple.mux,
+            gain: thermocouple.gain,
+            pga_bypass: false,
+            voltage_reference: Vref::Internal,
+            temperature_sensor_mode: false,
+            ..*self.config()
+        };
+        let sample = self.measure_with_config(&config, delay).await?;
+        if sample.is_saturated() {
+            return Err(Error::OutOfRange);
+        }
+
+        let volts = sample.to_volts(config.gain, INTERNAL_REFERENCE_UV as f32 * 1e-6);
+        let millivolts = self.calibration().correct(config.gain, volts) * 1e3;
+
+        let kind = thermocouple.kind;
+        let cold_junction_mv = kind.millivolts(cold_junction).ok_or(Error::OutOfRange)?;
+        let celsius = kind.celsius(millivolts + cold_junction_mv).ok_or(Error::OutOfRange)?;
+        Ok(ThermocoupleMeasurement {
+            celsius,
+            cold_junction_celsius: cold_junction,
+            millivolts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TYPES: [Type; 8] = [Type::B, Type::E, Type::J, Type::K, Type::N, Type::R, Type::S, Type::T];
+
+    /// NIST ITS-90 table values in degrees Celsius and millivolts, rounded to 1 µV
+    const NIST: [(Type, f32, f32); 36] = [
+        (Type::B, 100.0, 0.033),
+        (Type::B, 600.0, 1.792),
+        (Type::B, 1000.0, 4.834),
+        (Type::B, 1820.0, 13.820),
+        (Type::E, -200.0, -8.825),
+        (Type::E, 100.0, 6.319),
+        (Type::E, 500.0, 37.005),
+        (Type::E, 1000.0, 76.373),
+        (Type::J, -200.0, -7.890),
+        (Type::J, 100.0, 5.269),
+        (Type::J, 500.0, 27.393),
+        (Type::J, 1000.0, 57.953),
+        (Type::J, 1200.0, 69.553),
+        (Type::K, -200.0, -5.891),
+        (Type::K, 100.0, 4.096),
+        (Type::K, 500.0, 20.644),
+        (Type::K, 1000.0, 41.276),
+        (Type::K, 1372.0, 54.886),
+        (Type::N, -200.0, -3.990),
+        (Type::N, 100.0, 2.774),
+        (Type::N, 500.0, 16.748),
+        (Type::N, 1000.0, 36.256),
+        (Type::N, 1300.0, 47.513),
+        (Type::R, -50.0, -0.226),
+        (Type::R, 100.0, 0.647),
+        (Type::R, 500.0, 4.471),
+        (Type::R, 1000.0, 10.506),
+        (Type::R, 1768.0, 21.101),
+        (Type::S, -50.0, -0.236),
+        (Type::S, 100.0, 0.646),
+        (Type::S, 500.0, 4.233),
+        (Type::S, 1000.0, 9.587),
+        (Type::S, 1768.0, 18.693),
+        (Type::T, -200.0, -5.603),
+        (Type::T, 100.0, 4.279),
+        (Type::T, 400.0, 20.872),
+    ];
+
+    /// Temperature range covered by the inverse polynomials and the NIST inverse error bound,
+    /// widened slightly for `f32` rounding
+    fn inverse_range(kind: Type) -> (i32, i32, f32) {
+        match kind {
+            Type::B => (250, 1820, 0.035),
+            Type::E => (-200, 1000, 0.035),
+            Type::J => (-210, 1200, 0.055),
+            Type::K => (-200, 1372, 0.065),
+            Type::N => (-200, 1300, 0.045),
+            Type::R => (-50, 1768, 0.025),
+            Type::S => (-50, 1768, 0.025),
+            Type::T => (-200, 400, 0.045),
+        }
+    }
+
+    /// Evaluate one segment of a forward table, including the type K exponential term
+    fn forward_segment(kind: Type, segment: &Segment, celsius: f64) -> f64 {
+        let mut emf = segment.coefficients.iter().rev().fold(0.0, |acc, &c| acc * celsius + c);
+        if kind == Type::K && segment.max > 0.0 {
+            let [a0, a1, a2] = K_EXPONENTIAL;
+            emf += a0 * exp_negative(a1 * (celsius - a2) * (celsius - a2));
+        }
+        emf
+    }
+
+    #[test]
+    fn nist_table() {
+        for (kind, celsius, millivolts) in NIST {
+            let emf = kind.millivolts(celsius).unwrap();
+            assert!((emf - millivolts).abs() <= 0.0006, "{kind:?} {celsius} °C: {emf} mV");
+        }
+    }
+
+    #[test]
+    fn forward_segments_agree() {
+        for kind in TYPES {
+            for pair in kind.tables().0.segments.windows(2) {
+                let edge = pair[0].max as f64;
+                let below = forward_segment(kind, &pair[0], edge);
+                let above = forward_segment(kind, &pair[1], edge);
+                assert!((below - above).abs() <= 1e-6, "{kind:?} {edge} °C: {below} mV, {above} mV");
+            }
+        }
+    }
+
+    #[test]
+    fn inverse_segments_agree() {
+        for kind in TYPES {
+            for pair in kind.tables().1.segments.windows(2) {
+                let edge = pair[0].max as f64;
+                let below = pair[0].coefficients.iter().rev().fold(0.0, |acc, &c| acc * edge + c);
+                let above = pair[1].coefficients.iter().rev().fold(0.0, |acc, &c| acc * edge + c);
+                assert!((below - above).abs() <= 0.1, "{kind:?} {edge} mV: {below} °C, {above} °C");
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for kind in TYPES {
+            let (min, max, tolerance) = inverse_range(kind);
+            for celsius in min..=max {
+                let emf = kind.millivolts(celsius as f32).unwrap();
+                let t = kind.celsius(emf).unwrap();
+                assert!((t - celsius as f32).abs() <= tolerance, "{kind:?} {celsius} °C: {t} °C");
+            }
+        }
+    }
+
+    #[test]
+    fn range_limits() {
+        for kind in TYPES {
+            let (forward, inverse) = kind.tables();
+            let max = forward.segments.last().unwrap().max;
+            assert!(kind.millivolts(forward.min).is_some(), "{kind:?}");
+            assert!(kind.millivolts(max).is_some(), "{kind:?}");
+            assert_eq!(kind.millivolts(forward.min - 1.0), None, "{kind:?}");
+            assert_eq!(kind.millivolts(max + 1.0), None, "{kind:?}");
+            assert_eq!(kind.celsius(inverse.min - 0.01), None, "{kind:?}");
+            assert_eq!(kind.celsius(inverse.segments.last().unwrap().max + 0.01), None, "{kind:?}");
+        }
+    }
+}
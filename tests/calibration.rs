@@ -0,0 +1,53 @@
+use ads122c04_async::*;
+
+fn calibration() -> Calibration {
+    let mut calibration = Calibration::default();
+    for (i, gain) in [Gain::X1, Gain::X2, Gain::X4, Gain::X8, Gain::X16, Gain::X32, Gain::X64, Gain::X128]
+        .into_iter()
+        .enumerate()
+    {
+        calibration.set_offset(gain, -1000 * i as i32 - 1);
+        calibration.set_gain_correction_ppm(gain, 250 * i as i32 + 3);
+    }
+    calibration
+}
+
+#[test]
+fn round_trip() {
+    let calibration = calibration();
+    assert_eq!(Calibration::from_bytes(&calibration.to_bytes()), Ok(calibration));
+    assert_eq!(Calibration::from_bytes(&Calibration::default().to_bytes()), Ok(Calibration::default()));
+}
+
+#[test]
+fn layout() {
+    let bytes = calibration().to_bytes();
+    assert_eq!(&bytes[0..3], b"AC\x01");
+    // Gain::X2 offset and gain correction, little-endian
+    assert_eq!(bytes[11..15], (-1001i32).to_le_bytes());
+    assert_eq!(bytes[15..19], 253i32.to_le_bytes());
+}
+
+#[test]
+fn bad_magic() {
+    let mut bytes = calibration().to_bytes();
+    bytes[1] ^= 0x01;
+    assert_eq!(Calibration::from_bytes(&bytes), Err(CalibrationError::Magic));
+}
+
+#[test]
+fn bad_version() {
+    let mut bytes = calibration().to_bytes();
+    bytes[2] = 2;
+    assert_eq!(Calibration::from_bytes(&bytes), Err(CalibrationError::Version(2)));
+}
+
+#[test]
+fn bad_crc() {
+    let bytes = calibration().to_bytes();
+    for i in 3..Calibration::ENCODED_LEN {
+        let mut corrupted = bytes;
+        corrupted[i] ^= 0x01;
+        assert_eq!(Calibration::from_bytes(&corrupted), Err(CalibrationError::Crc), "byte {i}");
+    }
+}
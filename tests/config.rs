@@ -0,0 +1,102 @@
+use ads122c04_async::*;
+use embedded_hal_async::i2c::{ErrorType, I2c, Operation, SevenBitAddress};
+
+/// An I2C bus that must not be used
+struct NoBus;
+
+impl ErrorType for NoBus {
+    type Error = core::convert::Infallible;
+}
+
+impl I2c<SevenBitAddress> for NoBus {
+    async fn transaction(&mut self, address: u8, _: &mut [Operation<'_>]) -> Result<(), Self::Error> {
+        panic!("unexpected transaction with {address:#04x}");
+    }
+}
+
+fn assert_violates(config: Config, violation: Violation) {
+    let violations = config.validate().unwrap_err();
+    assert!(violations.contains(violation), "{config:?}");
+    assert_eq!(violations.iter().collect::<Vec<_>>(), [violation], "{config:?}");
+}
+
+#[test]
+fn default_is_valid() {
+    assert_eq!(Config::default().validate(), Ok(()));
+}
+
+#[test]
+fn pga_bypass_with_high_gain() {
+    let config = Config { pga_bypass: true, gain: Gain::X4, ..Config::default() };
+    assert_eq!(config.validate(), Ok(()));
+    assert_violates(Config { gain: Gain::X8, ..config }, Violation::PgaBypassWithHighGain);
+}
+
+#[test]
+fn idac_same_output() {
+    let config = Config {
+        current_dac: CurrentDac::I100uA,
+        current_mux_1: CurrentMux::Ain0,
+        current_mux_2: CurrentMux::Ain1,
+        ..Config::default()
+    };
+    assert_eq!(config.validate(), Ok(()));
+    assert_violates(Config { current_mux_2: CurrentMux::Ain0, ..config }, Violation::IdacSameOutput);
+}
+
+#[test]
+fn idac_not_routed() {
+    let config = Config { current_dac: CurrentDac::I100uA, ..Config::default() };
+    assert_violates(config, Violation::IdacNotRouted);
+}
+
+#[test]
+fn idac_current_off() {
+    let config = Config { current_mux_2: CurrentMux::Ain3, ..Config::default() };
+    assert_violates(config, Violation::IdacCurrentOff);
+}
+
+#[test]
+fn idac_on_unused_reference() {
+    let config = Config {
+        voltage_reference: Vref::External,
+        current_dac: CurrentDac::I250uA,
+        current_mux_1: CurrentMux::Refp,
+        ..Config::default()
+    };
+    assert_eq!(config.validate(), Ok(()));
+    assert_violates(Config { voltage_reference: Vref::Internal, ..config }, Violation::IdacOnUnusedReference);
+}
+
+#[test]
+fn monitor_with_pga() {
+    let config = Config { mux: Mux::Supply, pga_bypass: true, ..Config::default() };
+    assert_eq!(config.validate(), Ok(()));
+    assert_violates(Config { pga_bypass: false, ..config }, Violation::MonitorWithPga);
+    assert_violates(Config { mux: Mux::Vref, pga_bypass: false, ..config }, Violation::MonitorWithPga);
+}
+
+#[test]
+fn multiple_violations() {
+    let config = Config {
+        mux: Mux::Vref,
+        gain: Gain::X16,
+        pga_bypass: true,
+        current_dac: CurrentDac::I10uA,
+        ..Config::default()
+    };
+    let violations = config.validate().unwrap_err();
+    assert_eq!(
+        violations.iter().collect::<Vec<_>>(),
+        [Violation::PgaBypassWithHighGain, Violation::IdacNotRouted, Violation::MonitorWithPga]
+    );
+}
+
+#[test]
+fn apply_config_checked_rejects_invalid() {
+    let mut adc = ADS122C04::new(NoBus, 0x40);
+    let config = Config { current_dac: CurrentDac::I100uA, ..Config::default() };
+    let result = futures_lite::future::block_on(adc.apply_config_checked(&config));
+    assert_eq!(result, Err(Error::InvalidConfig(config.validate().unwrap_err())));
+    assert_eq!(*adc.config(), Config::default());
+}
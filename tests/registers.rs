@@ -0,0 +1,73 @@
+use ads122c04_async::*;
+
+/// Every byte either decodes and re-encodes to itself, or is rejected as reserved
+fn round_trip<R>(reg: u8) -> usize
+where
+    R: TryFrom<u8, Error = InvalidRegisterValue> + Into<u8>,
+{
+    let mut valid = 0;
+    for value in 0..=255u8 {
+        match R::try_from(value) {
+            Ok(r) => {
+                assert_eq!(r.into(), value, "register {reg} value {value:#010b}");
+                valid += 1;
+            }
+            Err(e) => assert_eq!(e, InvalidRegisterValue { reg, value }),
+        }
+    }
+    valid
+}
+
+#[test]
+fn register0_round_trip() {
+    // MUX 0b1111 is reserved
+    assert_eq!(round_trip::<Register0>(0), 15 * 16);
+}
+
+#[test]
+fn register1_round_trip() {
+    // DR 0b111 is reserved
+    assert_eq!(round_trip::<Register1>(1), 7 * 32);
+}
+
+#[test]
+fn register2_round_trip() {
+    // CRC 0b11 is reserved
+    assert_eq!(round_trip::<Register2>(2), 3 * 64);
+}
+
+#[test]
+fn register3_round_trip() {
+    // I1MUX and I2MUX 0b111 are reserved, and the two low bits must be zero
+    assert_eq!(round_trip::<Register3>(3), 7 * 7);
+}
+
+#[test]
+fn register1_turbo_mode_bit() {
+    let reg = Register1::try_from(0b0010_0000).unwrap();
+    assert_eq!(reg.data_rate, DataRate::N45);
+
+    let reg = Register1::try_from(0b0011_0000).unwrap();
+    assert_eq!(reg.data_rate, DataRate::T90);
+}
+
+#[test]
+fn register2_single_bit_fields() {
+    let reg = Register2::try_from(0b1000_0000).unwrap();
+    assert!(reg.data_ready);
+    assert!(!reg.data_count_enable);
+
+    let reg = Register2::try_from(0b0100_0000).unwrap();
+    assert!(!reg.data_ready);
+    assert!(reg.data_count_enable);
+    assert!(!reg.burn_out_source_enable);
+}
+
+#[test]
+fn register2_three_bit_idac() {
+    let reg = Register2::try_from(0b0000_0101).unwrap();
+    assert_eq!(reg.current_dac, CurrentDac::I500uA);
+
+    let reg = Register2::try_from(0b0000_0111).unwrap();
+    assert_eq!(reg.current_dac, CurrentDac::I1500uA);
+}
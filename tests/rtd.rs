@@ -0,0 +1,40 @@
+use ads122c04_async::rtd::*;
+
+/// IEC 60751 PT100 table values in degrees Celsius and ohms
+const PT100: [(f32, f32); 8] = [
+    (-200.0, 18.52),
+    (-100.0, 60.26),
+    (-50.0, 80.31),
+    (0.0, 100.0),
+    (100.0, 138.51),
+    (300.0, 212.05),
+    (600.0, 313.71),
+    (850.0, 390.48),
+];
+
+#[test]
+fn pt100_table() {
+    for (celsius, ohms) in PT100 {
+        let resistance = celsius_to_resistance(celsius, 100.0);
+        assert!((resistance - ohms).abs() <= 0.005, "{celsius} °C: {resistance} Ω");
+        let t = resistance_to_celsius(ohms, 100.0);
+        assert!((t - celsius).abs() <= 0.02, "{ohms} Ω: {t} °C");
+    }
+}
+
+#[test]
+fn pt1000_scales() {
+    for (celsius, ohms) in PT100 {
+        let resistance = celsius_to_resistance(celsius, 1000.0);
+        assert!((resistance - 10.0 * ohms).abs() <= 0.05, "{celsius} °C: {resistance} Ω");
+    }
+}
+
+#[test]
+fn round_trip() {
+    for tenths in (MIN_CELSIUS as i32 * 10)..=(MAX_CELSIUS as i32 * 10) {
+        let celsius = tenths as f32 / 10.0;
+        let t = resistance_to_celsius(celsius_to_resistance(celsius, 100.0), 100.0);
+        assert!((t - celsius).abs() <= 1e-3, "{celsius} °C: {t} °C");
+    }
+}